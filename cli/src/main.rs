@@ -1,6 +1,9 @@
 use clap::{crate_authors, crate_version, App, AppSettings, Arg};
 use lazy_static::lazy_static;
-use postman2openapi::{from_path, from_str, TranspileOptions};
+use postman2openapi::{
+    from_path_by_server, from_path_with_diagnostics, from_str_by_server, from_str_with_diagnostics,
+    postman, Diagnostic, EnumInference, TargetFormat, TranspileOptions,
+};
 use std::io::{stdin, Read};
 
 fn main() {
@@ -25,9 +28,73 @@ fn main() {
                 .long("output-format")
                 .help("The output format")
                 .value_name("format")
-                .possible_values(&["yaml", "json"])
+                .possible_values(["yaml", "json"])
                 .default_value("yaml"),
         )
+        .arg(
+            Arg::new("target-spec")
+                .short('t')
+                .long("target-spec")
+                .help("The specification version to produce")
+                .value_name("spec")
+                .possible_values(["openapi-3.0", "openapi-3.1", "swagger-2.0"])
+                .default_value("openapi-3.0"),
+        )
+        .arg(
+            Arg::new("environment")
+                .short('e')
+                .long("environment")
+                .help("A Postman environment export used to resolve variables; may be repeated")
+                .value_name("environment-file")
+                .takes_value(true)
+                .multiple_occurrences(true),
+        )
+        .arg(
+            Arg::new("globals")
+                .short('g')
+                .long("globals")
+                .help("A Postman globals export used to resolve variables; may be repeated")
+                .value_name("globals-file")
+                .takes_value(true)
+                .multiple_occurrences(true),
+        )
+        .arg(
+            Arg::new("infer-enums")
+                .long("infer-enums")
+                .help("Describe string properties that take only a few distinct values as enums"),
+        )
+        .arg(
+            Arg::new("extract-base-paths")
+                .long("extract-base-paths")
+                .help("Move the path prefix shared by every request to a server into its URL"),
+        )
+        .arg(
+            Arg::new("extract-schemas")
+                .long("extract-schemas")
+                .help("Move object schemas inferred more than once into components"),
+        )
+        .arg(
+            Arg::new("templatize-ids")
+                .long("templatize-ids")
+                .help("Turn literal identifiers in paths, such as /users/42, into path parameters"),
+        )
+        .arg(
+            Arg::new("cookie-auth")
+                .long("cookie-auth")
+                .help("Describe session cookies as API key security schemes"),
+        )
+        .arg(
+            Arg::new("exclude-disabled")
+                .long("exclude-disabled")
+                .help("Leave out query parameters, headers and form fields disabled in Postman"),
+        )
+        .arg(
+            Arg::new("split-by-server")
+                .long("split-by-server")
+                .help("Write one definition per server into the given directory")
+                .value_name("directory")
+                .takes_value(true),
+        )
         .arg(
             Arg::new("INPUT")
                 .value_name("input-file")
@@ -46,14 +113,76 @@ fn main() {
     let format = matches
         .value_of_t("output-format")
         .unwrap_or_else(|e| e.exit());
+    let target = matches
+        .value_of_t("target-spec")
+        .unwrap_or_else(|e| e.exit());
+
+    let mut environments = Vec::<postman::Environment>::new();
+    for filename in matches.values_of("globals").unwrap_or_default() {
+        match read_environment(filename) {
+            Ok(mut env) => {
+                env.scope = Some(postman::VariableScope::Globals);
+                environments.push(env);
+            }
+            Err(err) => {
+                eprintln!("{}: {}", filename, err);
+                return;
+            }
+        }
+    }
+    for filename in matches.values_of("environment").unwrap_or_default() {
+        match read_environment(filename) {
+            Ok(env) => environments.push(env),
+            Err(err) => {
+                eprintln!("{}: {}", filename, err);
+                return;
+            }
+        }
+    }
+
+    let options = TranspileOptions {
+        format,
+        target,
+        environments,
+        enum_inference: matches
+            .is_present("infer-enums")
+            .then(EnumInference::default),
+        extract_base_paths: matches.is_present("extract-base-paths"),
+        extract_shared_schemas: matches.is_present("extract-schemas"),
+        templatize_path_ids: matches.is_present("templatize-ids"),
+        exclude_disabled: matches.is_present("exclude-disabled"),
+        cookie_auth: matches.is_present("cookie-auth"),
+    };
+    if let Some(directory) = matches.value_of("split-by-server") {
+        let extension = match options.format {
+            TargetFormat::Json => "json",
+            TargetFormat::Yaml => "yaml",
+        };
+        let definitions = match &matches.value_of("INPUT") {
+            Some(filename) => from_path_by_server(filename, options),
+            None => match stdin().read_to_string(&mut buffer) {
+                Ok(_) => from_str_by_server(&buffer, options),
+                Err(_) => {
+                    eprintln!("postman2openapi: warning: recursive search of stdin");
+                    return;
+                }
+            },
+        };
+        match definitions {
+            Ok(definitions) => write_definitions(directory, extension, &definitions),
+            Err(err) => eprintln!("{}", err),
+        }
+        return;
+    }
+
     match &matches.value_of("INPUT") {
-        Some(filename) => match from_path(filename, TranspileOptions { format }) {
-            Ok(oas) => println!("{}", oas),
+        Some(filename) => match from_path_with_diagnostics(filename, options) {
+            Ok((oas, diagnostics)) => print_definition(&oas, &diagnostics),
             Err(err) => eprintln!("{}", err),
         },
         None => match stdin().read_to_string(&mut buffer) {
-            Ok(_) => match from_str(&buffer, TranspileOptions { format }) {
-                Ok(oas) => println!("{}", oas),
+            Ok(_) => match from_str_with_diagnostics(&buffer, options) {
+                Ok((oas, diagnostics)) => print_definition(&oas, &diagnostics),
                 Err(err) => eprintln!("{}", err),
             },
             Err(_) => eprintln!("postman2openapi: warning: recursive search of stdin"),
@@ -61,6 +190,70 @@ fn main() {
     };
 }
 
+fn print_definition(oas: &str, diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        eprintln!("postman2openapi: warning: {}", diagnostic);
+    }
+    println!("{}", oas);
+}
+
+fn write_definitions(
+    directory: &str,
+    extension: &str,
+    definitions: &[(String, String, Vec<Diagnostic>)],
+) {
+    if let Err(err) = std::fs::create_dir_all(directory) {
+        eprintln!("{}: {}", directory, err);
+        return;
+    }
+    let mut names = Vec::<String>::new();
+    for (server, oas, diagnostics) in definitions {
+        let base_name = server_file_name(server);
+        let mut name = base_name.clone();
+        let mut suffix = 1;
+        while names.contains(&name) {
+            suffix += 1;
+            name = format!("{}-{}", base_name, suffix);
+        }
+        let path = std::path::Path::new(directory).join(format!("{}.{}", name, extension));
+        for diagnostic in diagnostics {
+            eprintln!(
+                "postman2openapi: warning: {}: {}",
+                path.display(),
+                diagnostic
+            );
+        }
+        if let Err(err) = std::fs::write(&path, oas) {
+            eprintln!("{}: {}", path.display(), err);
+            return;
+        }
+        names.push(name);
+    }
+}
+
+/// Names the file for a server after its host and base path, e.g. `api.example.com_v1`.
+fn server_file_name(server: &str) -> String {
+    let url = server.split_once("://").map_or(server, |(_, url)| url);
+    let name = url
+        .chars()
+        .map(|c| match c {
+            'A'..='Z' | 'a'..='z' | '0'..='9' | '.' | '-' => c,
+            _ => '_',
+        })
+        .collect::<String>();
+    let name = name.trim_matches('_');
+    if name.is_empty() {
+        "openapi".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+fn read_environment(filename: &str) -> postman2openapi::Result<postman::Environment> {
+    let environment = std::fs::read_to_string(filename)?;
+    Ok(environment.parse::<postman::Environment>()?)
+}
+
 pub fn long_version() -> String {
     let hash = match option_env!("POSTMAN2OPENAPI_BUILD_GIT_HASH") {
         None => String::new(),
@@ -12,46 +12,196 @@ use convert_case::{Case, Casing};
 use gloo_utils::format::JsValueSerdeExt;
 use indexmap::{IndexMap, IndexSet};
 use openapi::v3_0::{self as openapi3, ObjectOrReference, Parameter, SecurityRequirement};
+use openapi::{escape_json_pointer, is_form_media_type};
 use postman::AuthType;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
 static VAR_REPLACE_CREDITS: usize = 20;
 
+/// The methods a path item can hold an operation for.
+static HTTP_METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
 lazy_static! {
     static ref VARIABLE_RE: regex::Regex = regex::Regex::new(r"\{\{([^{}]*?)\}\}").unwrap();
     static ref URI_TEMPLATE_VARIABLE_RE: regex::Regex =
         regex::Regex::new(r"\{([^{}]*?)\}").unwrap();
+    static ref DATE_TIME_RE: regex::Regex = regex::Regex::new(
+        r"^[0-9]{4}-[0-9]{2}-[0-9]{2}[Tt][0-9]{2}:[0-9]{2}:[0-9]{2}(\.[0-9]+)?([Zz]|[+-][0-9]{2}:[0-9]{2})$"
+    )
+    .unwrap();
+    static ref DATE_RE: regex::Regex = regex::Regex::new(r"^[0-9]{4}-[0-9]{2}-[0-9]{2}$").unwrap();
+    static ref EMAIL_RE: regex::Regex =
+        regex::Regex::new(r"^[^@ \t\r\n]+@[^@ \t\r\n]+\.[^@ \t\r\n]+$").unwrap();
+    static ref UUID_RE: regex::Regex = regex::Regex::new(
+        r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"
+    )
+    .unwrap();
+    static ref URI_RE: regex::Regex =
+        regex::Regex::new(r"^[a-zA-Z][a-zA-Z0-9+.-]*://[^ \t\r\n]+$").unwrap();
+    static ref INTEGER_KEY_RE: regex::Regex = regex::Regex::new(r"^[0-9]+$").unwrap();
+    static ref LOCALE_KEY_RE: regex::Regex =
+        regex::Regex::new(r"^([a-z]{2})(?:[-_][A-Z]{2})?$").unwrap();
+    static ref DECIMAL_RE: regex::Regex = regex::Regex::new(r"^-?[0-9]+\.[0-9]+$").unwrap();
+    static ref HEX_ID_RE: regex::Regex = regex::Regex::new(r"^[0-9a-fA-F]{8,}$").unwrap();
+    static ref SLUG_ID_RE: regex::Regex =
+        regex::Regex::new(r"^[a-z0-9]+(?:[-_][a-z0-9]+)+$").unwrap();
+    static ref PREFIXED_ID_RE: regex::Regex =
+        regex::Regex::new(r"^[a-z]{2,}_[A-Za-z0-9]{8,}$").unwrap();
+    static ref SESSION_COOKIE_RE: regex::Regex =
+        regex::Regex::new(
+            r"^(?:.*[._-])?(?:sid|(?:j|php)?sess(?:ion)?(?:[._-]?id)?|auth(?:[._-]?token)?|(?:access[._-]?)?token|jwt)$"
+        )
+        .unwrap();
+    static ref CSRF_COOKIE_RE: regex::Regex = regex::Regex::new(r"csrf|xsrf").unwrap();
+    static ref BASE64_RE: regex::Regex = regex::Regex::new(
+        r"^(?:[A-Za-z0-9+/]{4}){4,}(?:[A-Za-z0-9+/]{2}==|[A-Za-z0-9+/]{3}=)?$"
+    )
+    .unwrap();
 }
 
 #[derive(Default)]
 pub struct TranspileOptions {
     pub format: TargetFormat,
+    /// The version of the specification to produce.
+    pub target: TargetSpec,
+    /// Postman environment and globals exports used to resolve variables. Globals have the
+    /// lowest precedence, followed by collection variables, then environments in the order
+    /// given, then item variables.
+    pub environments: Vec<postman::Environment>,
+    /// When set, string properties that take only a few distinct values across the examples
+    /// are described with an `enum`.
+    pub enum_inference: Option<EnumInference>,
+    /// Moves the longest path prefix shared by every request to a server, such as `/api/v1`,
+    /// into the server's URL.
+    pub extract_base_paths: bool,
+    /// Moves object schemas inferred more than once, such as a user returned by several
+    /// operations, into `components.schemas` and refers to them with `$ref`.
+    pub extract_shared_schemas: bool,
+    /// Rewrites literal path segments that look like identifiers, such as `42` in `/users/42`,
+    /// as path parameters, keeping the literal values as examples.
+    pub templatize_path_ids: bool,
+    /// Leaves out query parameters, headers and form fields that are disabled in Postman,
+    /// rather than describing them as optional.
+    pub exclude_disabled: bool,
+    /// Describes session cookies sent in `Cookie` headers, such as `sessionid` or `JSESSIONID`,
+    /// as `apiKey` security schemes rather than cookie parameters.
+    pub cookie_auth: bool,
+}
+
+/// Thresholds deciding when the strings observed for a property are listed as an `enum`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnumInference {
+    /// The most distinct values a property may take.
+    pub max_values: usize,
+    /// The fewest values that must be observed, so that a property seen once or twice is not
+    /// mistaken for an enum.
+    pub min_occurrences: usize,
+}
+
+impl Default for EnumInference {
+    fn default() -> Self {
+        EnumInference {
+            max_values: 10,
+            min_occurrences: 3,
+        }
+    }
+}
+
+/// A problem found while transpiling that did not prevent a definition from being produced,
+/// such as a feature that cannot be represented in the target specification.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Where the problem was found, usually a JSON pointer into the generated definition.
+    pub location: Option<String>,
+    pub message: String,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{}: {}", location, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl From<openapi::v2_0::Warning> for Diagnostic {
+    fn from(warning: openapi::v2_0::Warning) -> Self {
+        Diagnostic {
+            location: Some(warning.location),
+            message: warning.message,
+        }
+    }
 }
 
 pub fn from_path(filename: &str, options: TranspileOptions) -> Result<String> {
+    from_path_with_diagnostics(filename, options).map(|(definition, _)| definition)
+}
+
+pub fn from_path_with_diagnostics(
+    filename: &str,
+    options: TranspileOptions,
+) -> Result<(String, Vec<Diagnostic>)> {
     let collection = std::fs::read_to_string(filename)?;
-    from_str(&collection, options)
+    from_str_with_diagnostics(&collection, options)
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 pub fn from_str(collection: &str, options: TranspileOptions) -> Result<String> {
+    from_str_with_diagnostics(collection, options).map(|(definition, _)| definition)
+}
+
+pub fn from_str_with_diagnostics(
+    collection: &str,
+    options: TranspileOptions,
+) -> Result<(String, Vec<Diagnostic>)> {
+    let postman_spec: postman::Spec = serde_json::from_str(collection)?;
+    let (oas_spec, diagnostics) = Transpiler::transpile_with_diagnostics(postman_spec, &options);
+    let oas_definition = serialize(&oas_spec, &options.format)?;
+    Ok((oas_definition, diagnostics))
+}
+
+/// Produces one definition per server for collections that span several hosts, returned with
+/// the URL of its server and the problems found while producing it.
+pub fn from_path_by_server(
+    filename: &str,
+    options: TranspileOptions,
+) -> Result<Vec<(String, String, Vec<Diagnostic>)>> {
+    let collection = std::fs::read_to_string(filename)?;
+    from_str_by_server(&collection, options)
+}
+
+/// Produces one definition per server for collections that span several hosts, returned with
+/// the URL of its server and the problems found while producing it.
+pub fn from_str_by_server(
+    collection: &str,
+    options: TranspileOptions,
+) -> Result<Vec<(String, String, Vec<Diagnostic>)>> {
     let postman_spec: postman::Spec = serde_json::from_str(collection)?;
-    let oas_spec = Transpiler::transpile(postman_spec);
-    let oas_definition = match options.format {
-        TargetFormat::Json => openapi::to_json(&oas_spec),
-        TargetFormat::Yaml => openapi::to_yaml(&oas_spec),
+    Transpiler::transpile_by_server(postman_spec, &options)
+        .into_iter()
+        .map(|(server, oas_spec, diagnostics)| {
+            Ok((server, serialize(&oas_spec, &options.format)?, diagnostics))
+        })
+        .collect()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn serialize(oas_spec: &openapi::OpenApi, format: &TargetFormat) -> Result<String> {
+    let oas_definition = match format {
+        TargetFormat::Json => openapi::to_json(oas_spec),
+        TargetFormat::Yaml => openapi::to_yaml(oas_spec),
     }?;
     Ok(oas_definition)
 }
 
 #[cfg(target_arch = "wasm32")]
-pub fn from_str(collection: &str, options: TranspileOptions) -> Result<String> {
-    let postman_spec: postman::Spec = serde_json::from_str(collection)?;
-    let oas_spec = Transpiler::transpile(postman_spec);
-    match options.format {
-        TargetFormat::Json => openapi::to_json(&oas_spec).map_err(|err| err.into()),
+fn serialize(oas_spec: &openapi::OpenApi, format: &TargetFormat) -> Result<String> {
+    match format {
+        TargetFormat::Json => openapi::to_json(oas_spec).map_err(|err| err.into()),
         TargetFormat::Yaml => Err(anyhow::anyhow!(
             "YAML is not supported for WebAssembly. Please convert from YAML to JSON."
         )),
@@ -101,23 +251,137 @@ impl std::str::FromStr for TargetFormat {
     }
 }
 
+#[derive(PartialEq, Eq, Debug, Default)]
+pub enum TargetSpec {
+    #[default]
+    OpenApi30,
+    OpenApi31,
+    Swagger20,
+}
+
+impl std::str::FromStr for TargetSpec {
+    type Err = &'static str;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "openapi-3.0" => Ok(TargetSpec::OpenApi30),
+            "openapi-3.1" => Ok(TargetSpec::OpenApi31),
+            "swagger-2.0" => Ok(TargetSpec::Swagger20),
+            _ => Err("invalid target specification"),
+        }
+    }
+}
+
 pub struct Transpiler<'a> {
     variable_map: &'a BTreeMap<String, serde_json::value::Value>,
 }
 
+/// Where an inferred object schema was seen, used to decide whether and under which name it is
+/// moved into `components.schemas`.
+#[derive(Default)]
+struct SchemaUsage {
+    count: usize,
+    body_names: Vec<String>,
+    property_names: Vec<String>,
+    folders: Vec<Option<String>>,
+    /// The resource of each path the schema is a body of, such as `User` for `/users/{id}`.
+    resources: Vec<Option<String>>,
+}
+
 struct TranspileState<'a> {
     oas: &'a mut openapi3::Spec,
     operation_ids: &'a mut BTreeMap<String, usize>,
     auth_stack: &'a mut Vec<SecurityRequirement>,
     hierarchy: &'a mut Vec<String>,
+    /// Descriptions of collection variables, used to document server variables.
+    variable_descriptions: &'a BTreeMap<String, String>,
+    /// The URLs of the servers each operation was called on, by path and method. Requests
+    /// without a host are recorded as `None`.
+    operation_servers: &'a mut OperationServers,
+    /// The cookies the `Set-Cookie` header of each response is generated from.
+    response_cookies: &'a mut ResponseCookies,
+    /// The key holding each path shape, along with every variant of the path merged into it.
+    path_shapes: &'a mut IndexMap<String, (String, IndexSet<String>)>,
+    /// The literal values seen at each position of the collection's paths, set when literal
+    /// identifiers are to be templated.
+    varying_segments: Option<&'a VaryingSegments>,
+    diagnostics: &'a mut Vec<Diagnostic>,
+    /// Whether the distinct values path parameters take are collected as enums.
+    infer_enums: bool,
+    /// Whether entries disabled in Postman are left out.
+    exclude_disabled: bool,
+    /// Whether session cookies are described as security schemes.
+    cookie_auth: bool,
 }
 
+/// The distinct literal values of path segments, keyed by the path with the segment replaced
+/// by `*` and any identifiers by `{}`, such as `users/*/orders/{}`.
+type VaryingSegments = BTreeMap<String, BTreeSet<String>>;
+
+type OperationServers = IndexMap<String, IndexMap<String, IndexSet<Option<String>>>>;
+
+/// The cookies set by the saved responses of each operation, by path, then method and status
+/// code.
+type ResponseCookies = BTreeMap<String, BTreeMap<(String, String), Vec<postman::Cookie>>>;
+
 impl<'a> Transpiler<'a> {
     pub fn new(variable_map: &'a BTreeMap<String, serde_json::value::Value>) -> Self {
         Self { variable_map }
     }
 
     pub fn transpile(spec: postman::Spec) -> openapi::OpenApi {
+        Self::transpile_with_options(spec, &TranspileOptions::default())
+    }
+
+    pub fn transpile_with_options(
+        spec: postman::Spec,
+        options: &TranspileOptions,
+    ) -> openapi::OpenApi {
+        Self::transpile_with_diagnostics(spec, options).0
+    }
+
+    /// Transpiles the collection, also returning the problems found along the way.
+    pub fn transpile_with_diagnostics(
+        spec: postman::Spec,
+        options: &TranspileOptions,
+    ) -> (openapi::OpenApi, Vec<Diagnostic>) {
+        let (oas, mut diagnostics) = Self::transform_collection(spec, options);
+        let (oas, finish_diagnostics) = Self::finish(oas, options);
+        diagnostics.extend(finish_diagnostics);
+        (oas, diagnostics)
+    }
+
+    /// Transpiles the collection into one definition per server, each holding only the
+    /// operations called on that server, along with the server's URL and the problems found.
+    pub fn transpile_by_server(
+        spec: postman::Spec,
+        options: &TranspileOptions,
+    ) -> Vec<(String, openapi::OpenApi, Vec<Diagnostic>)> {
+        let (oas, collection_diagnostics) = Self::transform_collection(spec, options);
+        Self::split_by_server(oas)
+            .into_iter()
+            .map(|(server, oas)| {
+                let mut diagnostics = collection_diagnostics
+                    .iter()
+                    .filter(|d| {
+                        d.location.as_ref().is_none_or(|location| {
+                            oas.paths.keys().any(|path| {
+                                location == &format!("#/paths/{}", escape_json_pointer(path))
+                            })
+                        })
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let (oas, finish_diagnostics) = Self::finish(oas, options);
+                diagnostics.extend(finish_diagnostics);
+                (server, oas, diagnostics)
+            })
+            .collect()
+    }
+
+    fn transform_collection(
+        spec: postman::Spec,
+        options: &TranspileOptions,
+    ) -> (openapi3::Spec, Vec<Diagnostic>) {
         let description = extract_description(&spec.info.description);
 
         let mut oas = openapi3::Spec {
@@ -139,29 +403,53 @@ impl<'a> Transpiler<'a> {
         };
 
         let mut variable_map = BTreeMap::<String, serde_json::value::Value>::new();
-        if let Some(var) = spec.variable {
-            for v in var {
-                if let Some(v_name) = v.key {
-                    if let Some(v_val) = v.value {
-                        if v_val != serde_json::Value::String("".to_string()) {
-                            variable_map.insert(v_name, v_val);
-                        }
-                    }
-                }
-            }
+        for env in options.environments.iter().filter(|env| env.is_globals()) {
+            extend_variable_map_from_environment(&mut variable_map, env);
+        }
+        if let Some(var) = &spec.variable {
+            extend_variable_map(&mut variable_map, var);
         };
+        for env in options.environments.iter().filter(|env| !env.is_globals()) {
+            extend_variable_map_from_environment(&mut variable_map, env);
+        }
+
+        let variable_descriptions = spec
+            .variable
+            .iter()
+            .flatten()
+            .filter_map(|v| Some((v.key.clone()?, extract_description(&v.description)?)))
+            .collect::<BTreeMap<_, _>>();
 
         let mut operation_ids = BTreeMap::<String, usize>::new();
+        let mut operation_servers = OperationServers::new();
+        let mut response_cookies = ResponseCookies::new();
+        let mut path_shapes = IndexMap::<String, (String, IndexSet<String>)>::new();
         let mut hierarchy = Vec::<String>::new();
+        let mut diagnostics = Vec::<Diagnostic>::new();
+
+        let transpiler = Transpiler {
+            variable_map: &variable_map,
+        };
+
+        let varying_segments = options.templatize_path_ids.then(|| {
+            let mut paths = Vec::new();
+            transpiler.collect_paths(&spec.item, &mut paths);
+            find_varying_segments(&paths)
+        });
         let mut state = TranspileState {
             oas: &mut oas,
             operation_ids: &mut operation_ids,
             hierarchy: &mut hierarchy,
             auth_stack: &mut Vec::<SecurityRequirement>::new(),
-        };
-
-        let transpiler = Transpiler {
-            variable_map: &mut variable_map,
+            variable_descriptions: &variable_descriptions,
+            operation_servers: &mut operation_servers,
+            response_cookies: &mut response_cookies,
+            path_shapes: &mut path_shapes,
+            varying_segments: varying_segments.as_ref(),
+            diagnostics: &mut diagnostics,
+            infer_enums: options.enum_inference.is_some(),
+            exclude_disabled: options.exclude_disabled,
+            cookie_auth: options.cookie_auth,
         };
 
         if let Some(auth) = spec.auth {
@@ -178,30 +466,123 @@ impl<'a> Transpiler<'a> {
         }
 
         transpiler.transform(&mut state, &spec.item);
+        Self::hoist_shared_parameters(&mut oas);
+        let renames = if options.extract_base_paths {
+            Self::extract_base_paths(&mut oas, &mut operation_servers)
+        } else {
+            BTreeMap::new()
+        };
+        Self::attach_operation_servers(&mut oas, &operation_servers);
+
+        diagnostics.extend(
+            path_shapes
+                .into_values()
+                .filter(|(_, variants)| variants.len() > 1)
+                .map(|(path, variants)| {
+                    let path = renames.get(&path).unwrap_or(&path);
+                    Diagnostic {
+                        location: Some(format!("#/paths/{}", escape_json_pointer(path))),
+                        message: format!(
+                            "paths that differ only in parameter names were merged: {}",
+                            variants.into_iter().collect::<Vec<_>>().join(", ")
+                        ),
+                    }
+                }),
+        );
+        (oas, diagnostics)
+    }
+
+    /// Completes the inferred schemas and produces the target specification.
+    fn finish(
+        mut oas: openapi3::Spec,
+        options: &TranspileOptions,
+    ) -> (openapi::OpenApi, Vec<Diagnostic>) {
+        let mut diagnostics = Self::describe_null_only_schemas(&mut oas);
+        if let Some(inference) = &options.enum_inference {
+            Self::infer_enums(&mut oas, inference);
+        }
+        if options.extract_shared_schemas {
+            Self::extract_shared_schemas(&mut oas);
+        }
 
-        openapi::OpenApi::V3_0(Box::new(oas))
+        let oas = match options.target {
+            TargetSpec::OpenApi30 => openapi::OpenApi::V3_0(Box::new(oas)),
+            TargetSpec::OpenApi31 => openapi::OpenApi::V3_1(Box::new(oas.into())),
+            TargetSpec::Swagger20 => {
+                let (swagger, warnings) = openapi::v2_0::Spec::from_v3_0(oas);
+                diagnostics.extend(warnings.into_iter().map(Diagnostic::from));
+                openapi::OpenApi::V2_0(Box::new(swagger))
+            }
+        };
+        (oas, diagnostics)
     }
 
     fn transform(&self, state: &mut TranspileState, items: &[postman::Items]) {
         for item in items {
-            if let Some(i) = &item.item {
-                let name = match &item.name {
-                    Some(n) => n,
-                    None => "<folder>",
-                };
-                let description = extract_description(&item.description);
+            match &item.variable {
+                Some(var) if !var.is_empty() => {
+                    // Item variables take precedence over everything else for the item and
+                    // any of its descendants.
+                    let mut variable_map = self.variable_map.clone();
+                    extend_variable_map(&mut variable_map, var);
+                    let transpiler = Transpiler {
+                        variable_map: &variable_map,
+                    };
+                    transpiler.transform_item(state, item);
+                }
+                _ => self.transform_item(state, item),
+            }
+        }
+    }
 
-                self.transform_folder(state, i, name, description, &item.auth);
-            } else {
-                let name = match &item.name {
-                    Some(n) => n,
-                    None => "<request>",
-                };
-                self.transform_request(state, item, name);
+    /// Gathers the resolved path segments of every request, with the same variable scoping as
+    /// `transform`.
+    fn collect_paths(&self, items: &[postman::Items], paths: &mut Vec<Vec<String>>) {
+        for item in items {
+            let variable_map;
+            let transpiler = match &item.variable {
+                Some(var) if !var.is_empty() => {
+                    let mut map = self.variable_map.clone();
+                    extend_variable_map(&mut map, var);
+                    variable_map = map;
+                    Transpiler {
+                        variable_map: &variable_map,
+                    }
+                }
+                _ => Transpiler {
+                    variable_map: self.variable_map,
+                },
+            };
+            if let Some(items) = &item.item {
+                transpiler.collect_paths(items, paths);
+            } else if let Some(request) = &item.request {
+                if let Some(url) = &request.to_request_class().url {
+                    if let Some(postman::UrlPath::UnionArray(p)) = &url.to_url_class().path {
+                        paths.push(transpiler.resolve_path_segments(p));
+                    }
+                }
             }
         }
     }
 
+    fn transform_item(&self, state: &mut TranspileState, item: &postman::Items) {
+        if let Some(i) = &item.item {
+            let name = match &item.name {
+                Some(n) => n,
+                None => "<folder>",
+            };
+            let description = extract_description(&item.description);
+
+            self.transform_folder(state, i, name, description, &item.auth);
+        } else {
+            let name = match &item.name {
+                Some(n) => n,
+                None => "<request>",
+            };
+            self.transform_request(state, item, name);
+        }
+    }
+
     fn transform_folder(
         &self,
         state: &mut TranspileState,
@@ -261,11 +642,32 @@ impl<'a> Transpiler<'a> {
     }
 
     fn transform_request(&self, state: &mut TranspileState, item: &postman::Items, name: &str) {
-        if let Some(postman::RequestUnion::RequestClass(request)) = &item.request {
-            if let Some(postman::Url::UrlClass(u)) = &request.url {
-                if let Some(postman::Host::StringArray(parts)) = &u.host {
-                    self.transform_server(state, u, parts);
-                }
+        if let Some(request) = &item.request {
+            let request = request.to_request_class();
+            let request = request.as_ref();
+            let method = match request.method.as_deref() {
+                Some(m) if !m.is_empty() => m.to_lowercase(),
+                _ => "get".to_string(),
+            };
+            if !HTTP_METHODS.contains(&method.as_str()) {
+                state.diagnostics.push(Diagnostic {
+                    location: None,
+                    message: format!(
+                        "{} requests cannot be described, so \"{name}\" was left out",
+                        method.to_uppercase()
+                    ),
+                });
+                return;
+            }
+            if let Some(url) = &request.url {
+                let u = url.to_url_class();
+                let u = u.as_ref();
+                let server = match &u.host {
+                    Some(postman::Host::StringArray(parts)) => {
+                        self.transform_server(state, u, parts)
+                    }
+                    _ => None,
+                };
 
                 let root_path: Vec<postman::PathElement> = vec![];
                 let paths = match &u.path {
@@ -292,48 +694,217 @@ impl<'a> Transpiler<'a> {
                     None
                 };
 
-                self.transform_paths(state, item, request, name, u, paths, security_requirement)
+                self.transform_paths(
+                    state,
+                    item,
+                    request,
+                    &method,
+                    name,
+                    u,
+                    paths,
+                    security_requirement,
+                    server,
+                )
+            }
+        }
+    }
+
+    /// Finds the path already holding requests of the same shape, such as `/admin/{sub}/{id}`
+    /// for `/admin/{resource}/{resourceId}`, and unifies the parameter names of both on the most
+    /// descriptive ones. Returns the path, its segments and the Postman path variables with the
+    /// unified names.
+    fn reconcile_path(
+        state: &mut TranspileState,
+        resolved_segments: Vec<String>,
+        variables: &Option<Vec<postman::Variable>>,
+    ) -> (String, Vec<String>, Option<Vec<postman::Variable>>) {
+        let path = "/".to_string() + &resolved_segments.join("/");
+        let shape = URI_TEMPLATE_VARIABLE_RE
+            .replace_all(&path, "{}")
+            .to_string();
+        let (existing, variants) = match state.path_shapes.get_mut(&shape) {
+            Some((existing, variants)) => (existing, variants),
+            None => {
+                state
+                    .path_shapes
+                    .insert(shape, (path.clone(), IndexSet::from([path.clone()])));
+                return (path, resolved_segments, variables.clone());
+            }
+        };
+        variants.insert(path.clone());
+        if *existing == path {
+            return (path, resolved_segments, variables.clone());
+        }
+
+        let names = path_parameter_names(&path);
+        let existing_names = path_parameter_names(existing);
+        // Longer names are taken to be the more descriptive, e.g. `userId` over `id`. When that
+        // would name two parameters alike, as when `{id}/{userId}` meets `{userId}/{id}`, the
+        // existing names are kept.
+        let mut unified = existing_names
+            .iter()
+            .zip(&names)
+            .map(|(existing, name)| {
+                if name.len() > existing.len() {
+                    name.clone()
+                } else {
+                    existing.clone()
+                }
+            })
+            .collect::<Vec<_>>();
+        if unified.iter().collect::<BTreeSet<_>>().len() < unified.len() {
+            unified = existing_names.clone();
+        }
+        let rename = |value: &str, index: &mut usize| {
+            URI_TEMPLATE_VARIABLE_RE
+                .replace_all(value, |_: &regex::Captures| {
+                    *index += 1;
+                    format!("{{{}}}", unified[*index - 1])
+                })
+                .to_string()
+        };
+
+        let unified_path = rename(existing, &mut 0);
+        if unified_path != *existing {
+            let previous = std::mem::replace(existing, unified_path.clone());
+            let renamed_parameters = existing_names
+                .iter()
+                .zip(&unified)
+                .collect::<BTreeMap<_, _>>();
+            state.oas.paths = std::mem::take(&mut state.oas.paths)
+                .into_iter()
+                .map(|(key, mut item)| {
+                    if key != previous {
+                        return (key, item);
+                    }
+                    for param in item.parameters.iter_mut().flatten() {
+                        if let ObjectOrReference::Object(param) = param {
+                            if let Some(name) = renamed_parameters.get(&param.name) {
+                                param.name = name.to_string();
+                            }
+                        }
+                    }
+                    (unified_path.clone(), item)
+                })
+                .collect();
+            if let Some(index) = state.operation_servers.get_index_of(&previous) {
+                let (_, servers) = state.operation_servers.swap_remove_index(index).unwrap();
+                state
+                    .operation_servers
+                    .insert(unified_path.clone(), servers);
+                state
+                    .operation_servers
+                    .move_index(state.operation_servers.len() - 1, index);
+            }
+            if let Some(cookies) = state.response_cookies.remove(&previous) {
+                state.response_cookies.insert(unified_path.clone(), cookies);
             }
         }
+
+        let mut index = 0;
+        let segments = resolved_segments
+            .iter()
+            .map(|segment| rename(segment, &mut index))
+            .collect::<Vec<_>>();
+        let renamed_variables = names.iter().zip(&unified).collect::<BTreeMap<_, _>>();
+        let variables = variables.as_ref().map(|variables| {
+            variables
+                .iter()
+                .map(|v| postman::Variable {
+                    key: v
+                        .key
+                        .as_ref()
+                        .map(|key| renamed_variables.get(key).map_or(key, |k| k).to_string()),
+                    ..v.clone()
+                })
+                .collect()
+        });
+        (unified_path, segments, variables)
     }
 
+    /// Adds the server the request was sent to, returning its URL.
     fn transform_server(
         &self,
         state: &mut TranspileState,
         url: &postman::UrlClass,
         parts: &[String],
-    ) {
+    ) -> Option<String> {
         let host = parts.join(".");
         let mut proto = "".to_string();
         if let Some(protocol) = &url.protocol {
             proto = format!("{protocol}://", protocol = protocol.clone());
         }
-        if let Some(s) = &mut state.oas.servers {
-            let mut server_url = format!("{proto}{host}");
-            server_url = self.resolve_variables(&server_url, VAR_REPLACE_CREDITS);
-            if !s.iter_mut().any(|srv| srv.url == server_url) {
-                let server = openapi3::Server {
-                    url: server_url,
-                    description: None,
-                    variables: None,
-                };
-                s.push(server);
-            }
+        let port = match &url.port {
+            Some(port) if !port.is_empty() => format!(":{port}"),
+            _ => "".to_string(),
+        };
+        let s = state.oas.servers.as_mut()?;
+        let server_url =
+            self.resolve_all_variables(&format!("{proto}{host}{port}"), VAR_REPLACE_CREDITS);
+        let server = self.generate_server(&server_url, state.variable_descriptions);
+        let server_url = server.url.clone();
+        if !s.iter_mut().any(|srv| srv.url == server.url) {
+            s.push(server);
         }
+        Some(server_url)
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn transform_paths(
+    /// Turns the placeholders left unresolved in a server URL into server variables. When the
+    /// whole URL is a placeholder, such as `{{baseUrl}}`, a relative server is used instead.
+    fn generate_server(
         &self,
-        state: &mut TranspileState,
-        item: &postman::Items,
-        request: &postman::RequestClass,
-        request_name: &str,
-        url: &postman::UrlClass,
-        paths: &[postman::PathElement],
-        security_requirement: Option<Vec<SecurityRequirement>>,
-    ) {
-        let resolved_segments = paths
+        url: &str,
+        descriptions: &BTreeMap<String, String>,
+    ) -> openapi3::Server {
+        if let Some(cap) = VARIABLE_RE.captures(url).filter(|cap| &cap[0] == url) {
+            return openapi3::Server {
+                url: "/".to_string(),
+                description: Some(format!(
+                    "The base URL is given by the `{}` variable, which could not be resolved.",
+                    &cap[0]
+                )),
+                variables: None,
+            };
+        }
+
+        let mut variables = BTreeMap::<String, openapi3::ServerVariable>::new();
+        let template = VARIABLE_RE.replace_all(url, |cap: &regex::Captures| {
+            let name = cap[1].to_string();
+            let is_protocol = url[cap.get(0).unwrap().end()..].starts_with("://");
+            // Only string values are substituted, so numbers such as ports remain to be used.
+            let default = match self.variable_map.get(&name) {
+                Some(value) if !value.is_string() && !value.is_null() => value.to_string(),
+                _ if is_protocol => "https".to_string(),
+                _ => name.clone(),
+            };
+            let description = descriptions.get(&name).cloned().or_else(|| {
+                Some(format!(
+                    "The value of the `{{{{{name}}}}}` variable, which could not be resolved."
+                ))
+            });
+            variables.insert(
+                name.clone(),
+                openapi3::ServerVariable {
+                    default,
+                    substitutions_enum: is_protocol
+                        .then(|| vec!["https".to_string(), "http".to_string()]),
+                    description,
+                },
+            );
+            format!("{{{name}}}")
+        });
+
+        openapi3::Server {
+            url: template.to_string(),
+            description: None,
+            variables: Some(variables).filter(|variables| !variables.is_empty()),
+        }
+    }
+
+    /// Resolves the variables of each path segment, turning `:name` segments and unresolved
+    /// placeholders into URI template variables.
+    fn resolve_path_segments(&self, paths: &[postman::PathElement]) -> Vec<String> {
+        paths
             .iter()
             .map(|segment| {
                 let mut seg = match segment {
@@ -352,13 +923,30 @@ impl<'a> Transpiler<'a> {
                     seg
                 }
             })
-            .collect::<Vec<String>>();
-        let segments = "/".to_string() + &resolved_segments.join("/");
+            .collect::<Vec<String>>()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn transform_paths(
+        &self,
+        state: &mut TranspileState,
+        item: &postman::Items,
+        request: &postman::RequestClass,
+        method: &str,
+        request_name: &str,
+        url: &postman::UrlClass,
+        paths: &[postman::PathElement],
+        security_requirement: Option<Vec<SecurityRequirement>>,
+        server: Option<String>,
+    ) {
+        let resolved_segments = self.resolve_path_segments(paths);
+        let (resolved_segments, variables) = match state.varying_segments {
+            Some(varying) => templatize_path_ids(resolved_segments, &url.variable, varying),
+            None => (resolved_segments, url.variable.clone()),
+        };
+        let (segments, resolved_segments, variables) =
+            Self::reconcile_path(state, resolved_segments, &variables);
 
-        // TODO: Because of variables, we can actually get duplicate paths.
-        // - /admin/{subresource}/{subresourceId}
-        // - /admin/{subresource2}/{subresource2Id}
-        // Throw a warning?
         if !state.oas.paths.contains_key(&segments) {
             state
                 .oas
@@ -367,17 +955,20 @@ impl<'a> Transpiler<'a> {
         }
 
         let path = state.oas.paths.get_mut(&segments).unwrap();
-        let method = match &request.method {
-            Some(m) => m.to_lowercase(),
-            None => "get".to_string(),
-        };
-        let op_ref = match method.as_str() {
-            "get" => &mut path.get,
+        state
+            .operation_servers
+            .entry(segments.clone())
+            .or_default()
+            .entry(method.to_string())
+            .or_default()
+            .insert(server);
+        let op_ref = match method {
             "post" => &mut path.post,
             "put" => &mut path.put,
             "delete" => &mut path.delete,
             "patch" => &mut path.patch,
             "options" => &mut path.options,
+            "head" => &mut path.head,
             "trace" => &mut path.trace,
             _ => &mut path.get,
         };
@@ -387,20 +978,10 @@ impl<'a> Transpiler<'a> {
         }
         let op = op_ref.as_mut().unwrap();
 
-        if let Some(security_requirement) = security_requirement {
-            if let Some(security) = &mut op.security {
-                for sr in security_requirement {
-                    if !security.contains(&sr) {
-                        security.push(sr);
-                    }
-                }
-            } else {
-                op.security = Some(security_requirement);
-            }
+        if let Some(path_params) = self.generate_path_parameters(&resolved_segments, &variables) {
+            Self::merge_parameters(&mut path.parameters, path_params, state.infer_enums);
         }
 
-        path.parameters = self.generate_path_parameters(&resolved_segments, &url.variable);
-
         if !is_merge {
             let mut op_id = request_name
                 .chars()
@@ -425,96 +1006,132 @@ impl<'a> Transpiler<'a> {
             op.operation_id = Some(op_id);
         }
 
-        if let Some(qp) = &url.query {
-            if let Some(mut query_params) = self.generate_query_parameters(qp) {
-                match &op.parameters {
-                    Some(params) => {
-                        let mut cloned = params.clone();
-                        for p1 in &mut query_params {
-                            if let ObjectOrReference::Object(p1) = p1 {
-                                let found = cloned.iter_mut().find(|p2| {
-                                    if let ObjectOrReference::Object(p2) = p2 {
-                                        p2.location == p1.location && p2.name == p1.name
-                                    } else {
-                                        false
-                                    }
-                                });
-                                if let Some(ObjectOrReference::Object(p2)) = found {
-                                    p2.schema = Some(Self::merge_schemas(
-                                        p2.schema.clone().unwrap(),
-                                        &p1.schema.clone().unwrap(),
-                                    ));
-                                } else {
-                                    cloned.push(ObjectOrReference::Object(p1.clone()));
-                                }
-                            }
-                        }
-                        op.parameters = Some(cloned);
-                    }
-                    None => op.parameters = Some(query_params),
-                };
-            }
-        }
+        let query_params = url
+            .query
+            .iter()
+            .flatten()
+            .filter(|qp| !(state.exclude_disabled && qp.disabled == Some(true)))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut request_params = self
+            .generate_query_parameters(&query_params)
+            .unwrap_or_default();
 
         let mut content_type: Option<String> = None;
+        let mut session_cookies = Vec::<String>::new();
 
         if let Some(postman::HeaderUnion::HeaderArray(headers)) = &request.header {
-            for header in headers
-                .iter()
-                .filter(|hdr| hdr.key.is_some() && hdr.value.is_some())
-            {
+            for header in headers.iter().filter(|hdr| {
+                hdr.key.is_some()
+                    && hdr.value.is_some()
+                    && !(state.exclude_disabled && hdr.disabled == Some(true))
+            }) {
                 let key = header.key.as_ref().unwrap().to_lowercase();
                 let value = header.value.as_ref().unwrap();
+                let is_enabled = header.disabled != Some(true);
                 if key == "accept" || key == "authorization" {
                     continue;
                 }
                 if key == "content-type" {
-                    let content_type_parts: Vec<&str> = value.split(';').collect();
-                    content_type = Some(content_type_parts[0].to_owned());
+                    if is_enabled {
+                        let content_type_parts: Vec<&str> = value.split(';').collect();
+                        content_type = Some(content_type_parts[0].to_owned());
+                    }
+                } else if key == "cookie" {
+                    let cookies = value
+                        .split(';')
+                        .filter_map(|cookie| cookie.split_once('='))
+                        .map(|(name, value)| (name.trim(), value.trim()))
+                        .filter(|(name, _)| !name.is_empty());
+                    for (name, value) in cookies {
+                        if state.cookie_auth && is_enabled && is_session_cookie(name) {
+                            session_cookies.push(name.to_owned());
+                            continue;
+                        }
+                        request_params.push(ObjectOrReference::Object(Parameter {
+                            location: "cookie".to_owned(),
+                            name: name.to_owned(),
+                            required: is_enabled.then_some(true),
+                            schema: Some(self.generate_scalar_schema(Some(value))),
+                            ..Parameter::default()
+                        }));
+                    }
                 } else {
-                    let param = Parameter {
+                    request_params.push(ObjectOrReference::Object(Parameter {
                         location: "header".to_owned(),
                         name: key.to_owned(),
                         description: extract_description(&header.description),
-                        schema: Some(openapi3::Schema {
-                            schema_type: Some("string".to_owned()),
-                            example: Some(serde_json::Value::String(value.to_owned())),
-                            ..openapi3::Schema::default()
-                        }),
+                        required: is_enabled.then_some(true),
+                        schema: Some(self.generate_string_schema(Some(value))),
                         ..Parameter::default()
-                    };
+                    }));
+                }
+            }
+        }
 
-                    if op.parameters.is_none() {
-                        op.parameters = Some(vec![ObjectOrReference::Object(param)]);
-                    } else {
-                        let params = op.parameters.as_mut().unwrap();
-                        let mut has_pushed = false;
-                        for p in params {
-                            if let ObjectOrReference::Object(p) = p {
-                                if p.name == param.name && p.location == param.location {
-                                    if let Some(schema) = &p.schema {
-                                        has_pushed = true;
-                                        p.schema = Some(Self::merge_schemas(
-                                            schema.clone(),
-                                            &param.schema.clone().unwrap(),
-                                        ));
-                                    }
-                                }
-                            }
-                        }
-                        if !has_pushed {
-                            op.parameters
-                                .as_mut()
-                                .unwrap()
-                                .push(ObjectOrReference::Object(param));
-                        }
-                    }
+        self.infer_comma_separated_lists(&mut op.parameters, &mut request_params);
+        Self::merge_request_parameters(&mut op.parameters, request_params, is_merge);
+
+        // Session cookies are sent together, and along with whatever else the request requires,
+        // so they are added to each of its requirements.
+        let inherited = state
+            .oas
+            .security
+            .clone()
+            .filter(|security| !security.is_empty())
+            .unwrap_or_else(|| vec![SecurityRequirement { requirement: None }]);
+        let security_requirement = if session_cookies.is_empty() {
+            security_requirement
+        } else {
+            let schemes = session_cookies
+                .iter()
+                .map(|cookie| {
+                    let name = Self::cookie_security_scheme(&mut state.oas.components, cookie);
+                    (name, vec![])
+                })
+                .collect::<BTreeMap<_, _>>();
+            let requirements = security_requirement.unwrap_or_else(|| inherited.clone());
+            Some(
+                requirements
+                    .into_iter()
+                    .map(|mut sr| {
+                        sr.requirement
+                            .get_or_insert_with(BTreeMap::new)
+                            .extend(schemes.clone());
+                        sr
+                    })
+                    .collect(),
+            )
+        };
+
+        // Session cookies are only required when every request merged into the operation sends
+        // them, so the requirements the others inherit are kept as an alternative.
+        let sends_cookies = !session_cookies.is_empty();
+        let requires_cookies = op.security.iter().flatten().any(|sr| {
+            sr.requirement
+                .iter()
+                .flat_map(|requirement| requirement.keys())
+                .any(|name| is_cookie_scheme(&state.oas.components, name))
+        });
+        let alternatives = match (security_requirement, op.security.is_some()) {
+            (Some(requirements), false) if is_merge && sends_cookies => {
+                Some([requirements, inherited].concat())
+            }
+            (Some(requirements), _) => Some(requirements),
+            (None, true) if requires_cookies => Some(inherited),
+            (None, _) => None,
+        };
+        if let Some(alternatives) = alternatives {
+            let security = op.security.get_or_insert_with(Vec::new);
+            for sr in alternatives {
+                if !security.contains(&sr) {
+                    security.push(sr);
                 }
             }
         }
 
         if let Some(body) = &request.body {
-            self.extract_request_body(body, op, request_name, content_type);
+            self.extract_request_body(body, op, request_name, content_type, state.exclude_disabled);
         }
 
         if !is_merge {
@@ -538,8 +1155,8 @@ impl<'a> Transpiler<'a> {
                         content_type = Some("text/plain".to_string());
                         if let Some(options) = body.options.clone() {
                             if let Some(raw_options) = options.raw {
-                                if raw_options.language.is_some() {
-                                    content_type = match raw_options.language.unwrap().as_str() {
+                                if let Some(language) = raw_options.language {
+                                    content_type = match language.as_str() {
                                         "xml" => Some("application/xml".to_string()),
                                         "json" => Some("application/json".to_string()),
                                         "html" => Some("text/html".to_string()),
@@ -548,7 +1165,13 @@ impl<'a> Transpiler<'a> {
                                 }
                             }
                         }
-                        self.extract_request_body(body, op, request_name, content_type);
+                        self.extract_request_body(
+                            body,
+                            op,
+                            request_name,
+                            content_type,
+                            state.exclude_disabled,
+                        );
                     }
                 }
                 let mut oas_response = openapi3::Response::default();
@@ -557,9 +1180,10 @@ impl<'a> Transpiler<'a> {
                 if let Some(name) = &r.name {
                     oas_response.description = Some(name.clone());
                 }
+                let mut oas_headers =
+                    BTreeMap::<String, openapi3::ObjectOrReference<openapi3::Header>>::new();
+                let mut cookies = r.cookie.clone().unwrap_or_default();
                 if let Some(postman::Headers::UnionArray(headers)) = &r.header {
-                    let mut oas_headers =
-                        BTreeMap::<String, openapi3::ObjectOrReference<openapi3::Header>>::new();
                     for h in headers {
                         if let postman::HeaderElement::Header(hdr) = h {
                             if hdr.key.is_none()
@@ -569,12 +1193,16 @@ impl<'a> Transpiler<'a> {
                             {
                                 continue;
                             }
+                            let value = hdr.value.as_ref().unwrap();
+                            if hdr.key.as_ref().unwrap().eq_ignore_ascii_case("set-cookie") {
+                                cookies.extend(parse_set_cookie(value));
+                                continue;
+                            }
                             let mut oas_header = openapi3::Header::default();
                             let header_schema = openapi3::Schema {
                                 schema_type: Some("string".to_string()),
-                                example: Some(serde_json::Value::String(
-                                    hdr.value.clone().unwrap().to_string(),
-                                )),
+                                format: infer_string_format(value),
+                                example: Some(serde_json::Value::String(value.to_string())),
                                 ..Default::default()
                             };
                             oas_header.schema = Some(header_schema);
@@ -585,9 +1213,9 @@ impl<'a> Transpiler<'a> {
                             );
                         }
                     }
-                    if !oas_headers.is_empty() {
-                        oas_response.headers = Some(oas_headers);
-                    }
+                }
+                if !oas_headers.is_empty() {
+                    oas_response.headers = Some(oas_headers);
                 }
                 let mut response_content = openapi3::MediaType::default();
                 if let Some(raw) = &r.body {
@@ -660,11 +1288,9 @@ impl<'a> Transpiler<'a> {
                         }
 
                         if let Some(headers) = new_response.headers {
-                            let mut cloned_headers = headers.clone();
-                            for (key, val) in headers {
-                                cloned_headers.insert(key, val);
-                            }
-                            existing_response.headers = Some(cloned_headers);
+                            let existing_headers =
+                                existing_response.headers.get_or_insert_with(BTreeMap::new);
+                            existing_headers.extend(headers);
                         }
 
                         let mut existing_content =
@@ -706,6 +1332,27 @@ impl<'a> Transpiler<'a> {
                     } else {
                         op.responses.insert(code.to_string(), oas_response);
                     }
+
+                    // The header describes every cookie set by the responses with this code,
+                    // including those of other requests merged into the operation.
+                    let response_cookies = state
+                        .response_cookies
+                        .entry(segments.clone())
+                        .or_default()
+                        .entry((method.to_string(), code.to_string()))
+                        .or_default();
+                    response_cookies.extend(cookies);
+                    if let Some(header) = generate_set_cookie_header(response_cookies) {
+                        op.responses
+                            .get_mut(&code.to_string())
+                            .unwrap()
+                            .headers
+                            .get_or_insert_with(BTreeMap::new)
+                            .insert(
+                                "Set-Cookie".to_string(),
+                                openapi3::ObjectOrReference::Object(header),
+                            );
+                    }
                 }
             }
         }
@@ -894,12 +1541,51 @@ impl<'a> Transpiler<'a> {
         security
     }
 
+    /// Registers an API key security scheme for a session cookie and returns its name. Each
+    /// cookie gets its own scheme, named `cookieAuth`, `cookieAuth2` and so on.
+    fn cookie_security_scheme(
+        components: &mut Option<openapi3::Components>,
+        cookie: &str,
+    ) -> String {
+        let security_schemes = components
+            .get_or_insert_with(openapi3::Components::default)
+            .security_schemes
+            .get_or_insert_with(BTreeMap::new);
+        let existing = security_schemes
+            .iter()
+            .find_map(|(name, scheme)| match scheme {
+                ObjectOrReference::Object(openapi3::SecurityScheme::ApiKey {
+                    name: key,
+                    location,
+                }) if key == cookie && location == "cookie" => Some(name.clone()),
+                _ => None,
+            });
+        if let Some(name) = existing {
+            return name;
+        }
+
+        let name = (1..)
+            .map(|n| match n {
+                1 => "cookieAuth".to_string(),
+                n => format!("cookieAuth{n}"),
+            })
+            .find(|name| !security_schemes.contains_key(name))
+            .unwrap();
+        let scheme = openapi3::SecurityScheme::ApiKey {
+            name: cookie.to_string(),
+            location: "cookie".to_string(),
+        };
+        security_schemes.insert(name.clone(), ObjectOrReference::Object(scheme));
+        name
+    }
+
     fn extract_request_body(
         &self,
         body: &postman::Body,
         op: &mut openapi3::Operation,
         name: &str,
         ct: Option<String>,
+        exclude_disabled: bool,
     ) {
         let mut content_type = ct;
         let mut request_body = if let Some(ObjectOrReference::Object(rb)) = op.request_body.as_mut()
@@ -936,8 +1622,7 @@ impl<'a> Transpiler<'a> {
                                     };
 
                                     if let Some(schema) = Self::generate_schema(&v) {
-                                        content.schema =
-                                            Some(openapi3::ObjectOrReference::Object(schema));
+                                        Self::merge_content_schema(content, schema);
                                     }
                                     example_val = v;
                                 }
@@ -949,14 +1634,13 @@ impl<'a> Transpiler<'a> {
                                 content_type = Some("text/plain".to_string());
                                 if let Some(options) = body.options.clone() {
                                     if let Some(raw_options) = options.raw {
-                                        if raw_options.language.is_some() {
-                                            content_type =
-                                                match raw_options.language.unwrap().as_str() {
-                                                    "xml" => Some("application/xml".to_string()),
-                                                    "json" => Some("application/json".to_string()),
-                                                    "html" => Some("text/html".to_string()),
-                                                    _ => Some("text/plain".to_string()),
-                                                }
+                                        if let Some(language) = raw_options.language {
+                                            content_type = match language.as_str() {
+                                                "xml" => Some("application/xml".to_string()),
+                                                "json" => Some("application/json".to_string()),
+                                                "html" => Some("text/html".to_string()),
+                                                _ => Some("text/plain".to_string()),
+                                            }
                                         }
                                     }
                                 }
@@ -1009,17 +1693,33 @@ impl<'a> Transpiler<'a> {
                         request_body.content.get_mut(ct).unwrap()
                     };
                     if let Some(urlencoded) = &body.urlencoded {
+                        // Disabled fields are described, but are neither required nor part of
+                        // the example.
+                        let mut fields = serde_json::Map::new();
                         let mut oas_data = serde_json::Map::new();
-                        for i in urlencoded {
+                        for i in urlencoded
+                            .iter()
+                            .filter(|i| !(exclude_disabled && i.disabled == Some(true)))
+                        {
                             if let Some(v) = &i.value {
                                 let value = serde_json::Value::String(v.to_string());
-                                oas_data.insert(i.key.clone(), value);
+                                if i.disabled != Some(true) {
+                                    oas_data.insert(i.key.clone(), value.clone());
+                                }
+                                fields.insert(i.key.clone(), value);
                             }
                         }
-                        let oas_obj = serde_json::Value::Object(oas_data);
-                        if let Some(schema) = Self::generate_schema(&oas_obj) {
-                            content.schema = Some(openapi3::ObjectOrReference::Object(schema));
+                        if let Some(mut schema) =
+                            Self::generate_schema(&serde_json::Value::Object(fields))
+                        {
+                            if schema.properties.is_some() {
+                                schema.required =
+                                    Some(oas_data.keys().cloned().collect::<Vec<_>>())
+                                        .filter(|required| !required.is_empty());
+                            }
+                            Self::merge_content_schema(content, schema);
                         }
+                        let oas_obj = serde_json::Value::Object(oas_data);
 
                         let examples = content.examples.clone().unwrap_or(
                             openapi3::MediaTypeExample::Examples {
@@ -1059,11 +1759,18 @@ impl<'a> Transpiler<'a> {
                         ..Default::default()
                     };
                     let mut properties = BTreeMap::<String, openapi3::Schema>::new();
+                    let mut required = Vec::new();
 
                     if let Some(formdata) = &body.formdata {
-                        for i in formdata {
+                        for i in formdata
+                            .iter()
+                            .filter(|i| !(exclude_disabled && i.disabled == Some(true)))
+                        {
                             if let Some(t) = &i.form_parameter_type {
                                 let is_binary = t.as_str() == "file";
+                                if i.disabled != Some(true) && !required.contains(&i.key) {
+                                    required.push(i.key.clone());
+                                }
                                 if let Some(v) = &i.value {
                                     let value = serde_json::Value::String(v.to_string());
                                     let prop_schema = Self::generate_schema(&value);
@@ -1090,7 +1797,8 @@ impl<'a> Transpiler<'a> {
                             // NOTE: Postman doesn't store the content type of multipart files. :(
                         }
                         schema.properties = Some(properties);
-                        content.schema = Some(openapi3::ObjectOrReference::Object(schema));
+                        schema.required = Some(required).filter(|required| !required.is_empty());
+                        Self::merge_content_schema(content, schema);
                     }
                 }
 
@@ -1160,10 +1868,42 @@ impl<'a> Transpiler<'a> {
         op.request_body = Some(openapi3::ObjectOrReference::Object(request_body));
     }
 
+    /// Merges a schema inferred from another example into the media type, so that bodies
+    /// observed across several requests are described together.
+    fn merge_content_schema(content: &mut openapi3::MediaType, schema: openapi3::Schema) {
+        content.schema = Some(ObjectOrReference::Object(match content.schema.take() {
+            Some(ObjectOrReference::Object(existing)) => Self::merge_schemas(existing, &schema),
+            _ => schema,
+        }));
+    }
+
     fn resolve_variables(&self, segment: &str, sub_replace_credits: usize) -> String {
         self.resolve_variables_with_replace_fn(segment, sub_replace_credits, |s| s)
     }
 
+    /// Resolves every placeholder that has a value, leaving the others in place.
+    fn resolve_all_variables(&self, segment: &str, sub_replace_credits: usize) -> String {
+        if sub_replace_credits == 0 {
+            return segment.to_string();
+        }
+
+        let mut is_replaced = false;
+        let resolved = VARIABLE_RE.replace_all(segment, |cap: &regex::Captures| {
+            match self.variable_map.get(&cap[1]).and_then(|v| v.as_str()) {
+                Some(value) => {
+                    is_replaced = true;
+                    value.to_string()
+                }
+                None => cap[0].to_string(),
+            }
+        });
+        if is_replaced {
+            self.resolve_all_variables(&resolved, sub_replace_credits - 1)
+        } else {
+            resolved.to_string()
+        }
+    }
+
     fn resolve_variables_with_replace_fn(
         &self,
         segment: &str,
@@ -1212,6 +1952,15 @@ impl<'a> Transpiler<'a> {
                     }
                 }
 
+                if let Some(values) = Self::dictionary_value_schema(&properties, 2) {
+                    schema.additional_properties =
+                        Some(ObjectOrReference::Object(Box::new(values)));
+                    return Some(schema);
+                }
+
+                if !properties.is_empty() {
+                    schema.required = Some(properties.keys().cloned().collect());
+                }
                 schema.properties = Some(properties);
                 Some(schema)
             }
@@ -1240,17 +1989,31 @@ impl<'a> Transpiler<'a> {
 
                 Some(schema)
             }
-            serde_json::Value::String(_) => {
+            serde_json::Value::String(s) => {
                 let schema = openapi3::Schema {
                     schema_type: Some("string".to_string()),
+                    format: infer_string_format(s),
                     example: Some(value.clone()),
                     ..Default::default()
                 };
                 Some(schema)
             }
-            serde_json::Value::Number(_) => {
+            serde_json::Value::Number(n) => {
+                let format = match n.as_i64() {
+                    Some(i) if i32::try_from(i).is_ok() => Some("int32"),
+                    _ if n.is_i64() || n.is_u64() => Some("int64"),
+                    _ => None,
+                };
                 let schema = openapi3::Schema {
-                    schema_type: Some("number".to_string()),
+                    schema_type: Some(
+                        if format.is_some() {
+                            "integer"
+                        } else {
+                            "number"
+                        }
+                        .to_string(),
+                    ),
+                    format: format.map(String::from),
                     example: Some(value.clone()),
                     ..Default::default()
                 };
@@ -1276,6 +2039,17 @@ impl<'a> Transpiler<'a> {
     }
 
     fn merge_schemas(mut original: openapi3::Schema, new: &openapi3::Schema) -> openapi3::Schema {
+        // A null observation only makes the other observations nullable.
+        if is_null_schema(new) {
+            original.nullable = Some(true);
+            return original;
+        }
+        if is_null_schema(&original) {
+            let mut merged = new.clone();
+            merged.nullable = Some(true);
+            return merged;
+        }
+
         // If the new schema has a nullable Option but the original doesn't,
         // set the original nullable to the new one.
         if original.nullable.is_none() && new.nullable.is_some() {
@@ -1300,6 +2074,48 @@ impl<'a> Transpiler<'a> {
         // Reset the schema type.
         if original.schema_type.is_none() && new.schema_type.is_some() && new.any_of.is_none() {
             original.schema_type = new.schema_type.clone();
+            original.format = new.format.clone();
+        }
+
+        match (original.schema_type.as_deref(), new.schema_type.as_deref()) {
+            // Integers widen to numbers rather than becoming a union of both.
+            (Some("integer"), Some("number")) | (Some("number"), Some("integer")) => {
+                original.schema_type = Some("number".to_string());
+                original.format = None;
+            }
+            (Some("integer"), Some("integer")) if original.format != new.format => {
+                original.format = Some("int64".to_string());
+            }
+            // A format only holds if every example agrees on it.
+            (original_type, new_type)
+                if original_type == new_type && original.format != new.format =>
+            {
+                original.format = None;
+            }
+            _ => {}
+        }
+
+        // Dictionaries merge their values, taking in the entries of examples that had too few
+        // keys to be recognized as dictionaries themselves.
+        if original.schema_type.as_deref() == Some("object")
+            && new.schema_type.as_deref() == Some("object")
+            && (original.additional_properties.is_some() || new.additional_properties.is_some())
+        {
+            if let (Some(values), Some(new_values)) = (
+                Self::dictionary_values(&original),
+                Self::dictionary_values(new),
+            ) {
+                let values = match (values, new_values) {
+                    (Some(values), Some(new_values)) => {
+                        Some(Self::merge_schemas(values, &new_values))
+                    }
+                    (values, new_values) => values.or(new_values),
+                };
+                original.properties = None;
+                original.required = None;
+                original.additional_properties =
+                    values.map(|values| ObjectOrReference::Object(Box::new(values)));
+            }
         }
 
         // If both types are objects, merge the schemas of each property.
@@ -1319,6 +2135,20 @@ impl<'a> Transpiler<'a> {
                                 original_properties.insert(key.to_string(), val.clone());
                             }
                         }
+
+                        // A property is only required if every example has it.
+                        original.required = original
+                            .required
+                            .take()
+                            .map(|required| {
+                                required
+                                    .into_iter()
+                                    .filter(|key| {
+                                        new.required.as_ref().is_some_and(|r| r.contains(key))
+                                    })
+                                    .collect::<Vec<_>>()
+                            })
+                            .filter(|required| !required.is_empty());
                     }
                 }
             }
@@ -1326,7 +2156,8 @@ impl<'a> Transpiler<'a> {
 
         if let Some(ref original_type) = original.schema_type {
             if let Some(ref new_type) = new.schema_type {
-                if new_type != original_type {
+                let is_widened = original_type == "number" && new_type == "integer";
+                if new_type != original_type && !is_widened {
                     let cloned = original.clone();
                     original.schema_type = None;
                     original.properties = None;
@@ -1342,154 +2173,1779 @@ impl<'a> Transpiler<'a> {
         original
     }
 
-    fn generate_path_parameters(
-        &self,
-        resolved_segments: &[String],
-        postman_variables: &Option<Vec<postman::Variable>>,
-    ) -> Option<Vec<openapi3::ObjectOrReference<openapi3::Parameter>>> {
-        let params: Vec<openapi3::ObjectOrReference<openapi3::Parameter>> = resolved_segments
-            .iter()
-            .flat_map(|segment| {
-                URI_TEMPLATE_VARIABLE_RE
-                    .captures_iter(segment.as_str())
-                    .map(|capture| {
-                        let var = capture.get(1).unwrap().as_str();
-                        let mut param = Parameter {
-                            name: var.to_owned(),
-                            location: "path".to_owned(),
-                            required: Some(true),
-                            ..Parameter::default()
-                        };
+    /// Recognizes objects used as maps, keyed by identifiers or locales rather than by fixed
+    /// property names, and merges the schemas of their values.
+    fn dictionary_value_schema(
+        properties: &BTreeMap<String, openapi3::Schema>,
+        min_keys: usize,
+    ) -> Option<openapi3::Schema> {
+        if properties.len() < min_keys {
+            return None;
+        }
+        let mut kinds = properties.keys().map(|key| dictionary_key_kind(key));
+        let kind = kinds.next().flatten()?;
+        if !kinds.all(|k| k == Some(kind)) {
+            return None;
+        }
+        // `id` is the code for Indonesian as well as the most common property name.
+        if kind == "locale" && properties.contains_key("id") {
+            return None;
+        }
+        let types = properties
+            .values()
+            .filter(|schema| !is_null_schema(schema))
+            // Integers widen to numbers when merged, so they don't make the values mixed.
+            .map(|schema| match schema.schema_type.as_deref() {
+                Some("integer") => Some("number"),
+                schema_type => schema_type,
+            })
+            .collect::<IndexSet<_>>();
+        if types.len() != 1 || types.first() == Some(&None) {
+            return None;
+        }
+        properties
+            .values()
+            .cloned()
+            .reduce(|values, schema| Self::merge_schemas(values, &schema))
+    }
 
-                        let mut schema = openapi3::Schema {
-                            schema_type: Some("string".to_string()),
-                            ..Default::default()
-                        };
-                        if let Some(path_val) = &postman_variables {
-                            if let Some(p) = path_val.iter().find(|p| match &p.key {
-                                Some(k) => k == var,
-                                _ => false,
-                            }) {
-                                param.description = extract_description(&p.description);
-                                if let Some(pval) = &p.value {
-                                    if let Some(pval_val) = pval.as_str() {
-                                        schema.example = Some(serde_json::Value::String(
-                                            self.resolve_variables(pval_val, VAR_REPLACE_CREDITS),
-                                        ));
-                                    }
-                                }
-                            }
+    /// The schema of the values of an object treated as a dictionary, which is `Some(None)` when
+    /// the object was empty, or `None` when the object can't be read as a dictionary.
+    fn dictionary_values(schema: &openapi3::Schema) -> Option<Option<openapi3::Schema>> {
+        match (&schema.additional_properties, &schema.properties) {
+            (Some(ObjectOrReference::Object(values)), _) => Some(Some(values.as_ref().clone())),
+            (Some(ObjectOrReference::Ref { .. }), _) => None,
+            (None, Some(properties)) if properties.is_empty() => Some(None),
+            (None, Some(properties)) => Self::dictionary_value_schema(properties, 1).map(Some),
+            (None, None) => Some(None),
+        }
+    }
+
+    /// Moves object schemas that were inferred more than once into `components.schemas`,
+    /// replacing every copy with a reference. Schemas are compared by structure, ignoring
+    /// examples, which are left out of the shared schema. Names come from the properties holding
+    /// the object (`User`, or `Address` for `billingAddress` and `shippingAddress`), the folder
+    /// or the resource shared by every operation using it, or the operation using it
+    /// (`GetUserResponse`), and are otherwise `Schema`.
+    fn extract_shared_schemas(oas: &mut openapi3::Spec) {
+        let mut usages = IndexMap::<String, SchemaUsage>::new();
+        for (path, item) in oas.paths.iter() {
+            let resource = path
+                .rsplit('/')
+                .find(|segment| !segment.is_empty() && !segment.contains('{'))
+                .map(|segment| singularize(&segment.to_case(Case::Pascal)));
+            for op in Self::operations(item) {
+                let op_name = op
+                    .operation_id
+                    .clone()
+                    .unwrap_or_default()
+                    .to_case(Case::Pascal);
+                let folder = op.tags.as_ref().and_then(|tags| tags.last().cloned());
+                if let Some(ObjectOrReference::Object(body)) = &op.request_body {
+                    for (media_type, content) in &body.content {
+                        if let (false, Some(ObjectOrReference::Object(schema))) =
+                            (is_form_media_type(media_type), &content.schema)
+                        {
+                            let name = format!("{op_name}Request");
+                            let context = (&folder, &resource);
+                            Self::collect_schema_usages(schema, &name, context, true, &mut usages);
                         }
-                        param.schema = Some(schema);
-                        openapi3::ObjectOrReference::Object(param)
+                    }
+                }
+                for response in op.responses.values() {
+                    for (media_type, content) in response.content.iter().flatten() {
+                        if let (false, Some(ObjectOrReference::Object(schema))) =
+                            (is_form_media_type(media_type), &content.schema)
+                        {
+                            let name = format!("{op_name}Response");
+                            let context = (&folder, &resource);
+                            Self::collect_schema_usages(schema, &name, context, true, &mut usages);
+                        }
+                    }
+                }
+            }
+        }
+
+        let existing = oas
+            .components
+            .as_ref()
+            .and_then(|components| components.schemas.as_ref());
+        let mut names = BTreeMap::<String, String>::new();
+        for (fingerprint, usage) in usages.into_iter().filter(|(_, usage)| usage.count > 1) {
+            let folder = usage.folders[0].clone().filter(|folder| {
+                usage.folders.iter().all(|f| f.as_ref() == Some(folder)) && !folder.is_empty()
+            });
+            // Only a name every usage agrees on is kept, so that no copy refers to a schema
+            // named after another one.
+            let resource = usage
+                .resources
+                .first()
+                .cloned()
+                .flatten()
+                .filter(|resource| {
+                    usage.resources.len() == usage.count
+                        && usage.resources.iter().all(|r| r.as_ref() == Some(resource))
+                });
+            let base_name = common_word_suffix(&usage.property_names)
+                .or_else(|| folder.map(|folder| singularize(&folder.to_case(Case::Pascal))))
+                .or(resource)
+                .or_else(|| {
+                    common_word_suffix(&usage.body_names)
+                        .filter(|name| name != "Request" && name != "Response")
+                })
+                .unwrap_or_else(|| "Schema".to_string());
+            let mut name = base_name.clone();
+            let mut suffix = 1;
+            while existing.is_some_and(|schemas| schemas.contains_key(&name))
+                || names.values().any(|n| n == &name)
+            {
+                suffix += 1;
+                name = format!("{base_name}{suffix}");
+            }
+            names.insert(fingerprint, name);
+        }
+        if names.is_empty() {
+            return;
+        }
+
+        let schemas = oas
+            .components
+            .get_or_insert_with(Default::default)
+            .schemas
+            .get_or_insert_with(BTreeMap::new);
+
+        for item in oas.paths.values_mut() {
+            for (_, op) in Self::operations_mut(item) {
+                let mut contents = vec![];
+                if let Some(ObjectOrReference::Object(body)) = &mut op.request_body {
+                    contents.extend(body.content.iter_mut());
+                }
+                for response in op.responses.values_mut() {
+                    contents.extend(response.content.iter_mut().flatten());
+                }
+                for (media_type, content) in contents {
+                    if is_form_media_type(media_type) {
+                        continue;
+                    }
+                    if let Some(ObjectOrReference::Object(schema)) = &mut content.schema {
+                        Self::replace_shared_schemas(schema, &names, schemas);
+                        if let Some(ref_path) = schema.ref_path.clone() {
+                            content.schema = Some(ObjectOrReference::Ref { ref_path });
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Moves the longest path prefix shared by every request to a server into the server's URL.
+    /// Servers that share a path are treated together, and only literal segments are moved,
+    /// always leaving at least one segment in every path.
+    fn extract_base_paths(
+        oas: &mut openapi3::Spec,
+        operation_servers: &mut OperationServers,
+    ) -> BTreeMap<String, String> {
+        let mut groups = Vec::<(IndexSet<Option<String>>, IndexSet<String>)>::new();
+        for (path, methods) in operation_servers.iter() {
+            let mut group = (
+                methods.values().flatten().cloned().collect::<IndexSet<_>>(),
+                IndexSet::from([path.clone()]),
+            );
+            let (connected, others) = groups
+                .into_iter()
+                .partition::<Vec<_>, _>(|(servers, _)| !servers.is_disjoint(&group.0));
+            for (servers, paths) in connected {
+                group.0.extend(servers);
+                group.1.extend(paths);
+            }
+            groups = others;
+            groups.push(group);
+        }
+
+        let mut renames = BTreeMap::<String, String>::new();
+        let mut server_renames = BTreeMap::<String, String>::new();
+        for (servers, paths) in groups {
+            // Requests without a host have no server to take the prefix.
+            if servers.contains(&None) {
+                continue;
+            }
+            let prefix = paths
+                .iter()
+                .map(|path| {
+                    let segments = path.split('/').skip(1).collect::<Vec<_>>();
+                    segments[..segments.len() - 1]
+                        .iter()
+                        .take_while(|segment| !segment.is_empty() && !segment.contains('{'))
+                        .copied()
+                        .collect::<Vec<_>>()
+                })
+                .reduce(|prefix, segments| {
+                    prefix
+                        .into_iter()
+                        .zip(segments)
+                        .take_while(|(a, b)| a == b)
+                        .map(|(a, _)| a)
+                        .collect()
+                })
+                .unwrap_or_default();
+            if prefix.is_empty() {
+                continue;
+            }
+            let prefix = format!("/{}", prefix.join("/"));
+            let group_renames = paths
+                .iter()
+                .map(|path| (path.clone(), path[prefix.len()..].to_string()))
+                .collect::<BTreeMap<_, _>>();
+            // Leave the group alone rather than merge its paths into paths of another server.
+            if group_renames.values().any(|renamed| {
+                oas.paths.contains_key(renamed) && !group_renames.contains_key(renamed)
+                    || renames.values().any(|r| r == renamed)
+            }) {
+                continue;
+            }
+            renames.extend(group_renames);
+            for server in servers.into_iter().flatten() {
+                let url = format!("{}{prefix}", server.trim_end_matches('/'));
+                server_renames.insert(server, url);
+            }
+        }
+
+        for server in oas.servers.iter_mut().flatten() {
+            if let Some(url) = server_renames.get(&server.url) {
+                server.url = url.clone();
+            }
+        }
+        oas.paths = std::mem::take(&mut oas.paths)
+            .into_iter()
+            .map(|(path, item)| (renames.get(&path).cloned().unwrap_or(path), item))
+            .collect();
+        *operation_servers = std::mem::take(operation_servers)
+            .into_iter()
+            .map(|(path, methods)| {
+                let methods = methods
+                    .into_iter()
+                    .map(|(method, servers)| {
+                        let servers = servers
+                            .into_iter()
+                            .map(|server| {
+                                server.map(|url| server_renames.get(&url).cloned().unwrap_or(url))
+                            })
+                            .collect();
+                        (method, servers)
                     })
+                    .collect();
+                (renames.get(&path).cloned().unwrap_or(path), methods)
             })
             .collect();
+        renames
+    }
 
-        if !params.is_empty() {
-            Some(params)
-        } else {
-            None
+    /// Lists the servers on path items and operations that were only called on some of the
+    /// servers of a collection spanning several hosts. Operations also sent without a host are
+    /// left to the top-level servers.
+    fn attach_operation_servers(oas: &mut openapi3::Spec, operation_servers: &OperationServers) {
+        let servers = oas.servers.clone().unwrap_or_default();
+        if servers.len() < 2 {
+            return;
         }
-    }
+        let subset = |urls: &IndexSet<&String>| {
+            Some(
+                servers
+                    .iter()
+                    .filter(|server| urls.contains(&server.url))
+                    .cloned()
+                    .collect::<Vec<_>>(),
+            )
+            .filter(|subset| !subset.is_empty() && subset.len() < servers.len())
+        };
 
-    fn generate_query_parameters(
-        &self,
-        query_params: &[postman::QueryParam],
-    ) -> Option<Vec<openapi3::ObjectOrReference<openapi3::Parameter>>> {
-        let mut keys = vec![];
-        let params = query_params
-            .iter()
-            .filter_map(|qp| match qp.key {
-                Some(ref key) => {
-                    if keys.contains(&key.as_str()) {
-                        return None;
+        for (path, methods) in operation_servers {
+            let item = match oas.paths.get_mut(path) {
+                Some(item) => item,
+                None => continue,
+            };
+            let method_urls = methods
+                .iter()
+                .map(|(method, urls)| {
+                    let urls = if urls.contains(&None) {
+                        None
+                    } else {
+                        Some(urls.iter().flatten().collect::<IndexSet<_>>())
+                    };
+                    (method, urls)
+                })
+                .collect::<Vec<_>>();
+            if let Some((_, Some(urls))) = method_urls.first() {
+                if method_urls.iter().all(|(_, u)| u.as_ref() == Some(urls)) {
+                    item.servers = subset(urls);
+                    continue;
+                }
+            }
+            for (method, urls) in method_urls {
+                if let Some(urls) = urls {
+                    if let Some((_, op)) = Self::operations_mut(item).find(|(m, _)| m == method) {
+                        op.servers = subset(&urls);
                     }
+                }
+            }
+        }
+    }
 
-                    keys.push(key);
-                    let param = Parameter {
-                        name: key.to_owned(),
-                        description: extract_description(&qp.description),
-                        location: "query".to_owned(),
-                        schema: Some(openapi3::Schema {
-                            schema_type: Some("string".to_string()),
-                            example: qp.value.as_ref().map(|pval| {
-                                serde_json::Value::String(
-                                    self.resolve_variables(pval, VAR_REPLACE_CREDITS),
-                                )
-                            }),
-                            ..openapi3::Schema::default()
-                        }),
-                        ..Parameter::default()
-                    };
+    /// Splits the definition into one per server, keeping the operations that apply to it.
+    fn split_by_server(oas: openapi3::Spec) -> Vec<(String, openapi3::Spec)> {
+        let servers = oas.servers.clone().unwrap_or_default();
+        if servers.len() < 2 {
+            let url = servers.first().map(|s| s.url.clone()).unwrap_or_default();
+            return vec![(url, oas)];
+        }
 
-                    Some(openapi3::ObjectOrReference::Object(param))
+        servers
+            .into_iter()
+            .map(|server| {
+                let mut part = oas.clone();
+                part.paths.retain(|_, item| {
+                    let path_servers = item.servers.take();
+                    for (_, slot) in Self::operation_slots_mut(item) {
+                        let applies = match slot {
+                            Some(op) => op
+                                .servers
+                                .take()
+                                .or_else(|| path_servers.clone())
+                                .is_none_or(|servers| servers.iter().any(|s| s.url == server.url)),
+                            None => continue,
+                        };
+                        if !applies {
+                            *slot = None;
+                        }
+                    }
+                    Self::operations(item).next().is_some()
+                });
+                let used_tags = part
+                    .paths
+                    .values()
+                    .flat_map(Self::operations)
+                    .flat_map(|op| op.tags.iter().flatten())
+                    .cloned()
+                    .collect::<IndexSet<_>>();
+                if let Some(tags) = &mut part.tags {
+                    tags.retain(|tag| used_tags.contains(&tag.name));
                 }
-                None => None,
+                part.servers = Some(vec![server.clone()]);
+                (server.url, part)
             })
-            .collect::<Vec<openapi3::ObjectOrReference<openapi3::Parameter>>>();
+            .collect()
+    }
 
-        if !params.is_empty() {
-            Some(params)
-        } else {
-            None
+    /// Replaces schemas that were only ever observed as `null` with a placeholder, since no
+    /// type can be inferred for them.
+    fn describe_null_only_schemas(oas: &mut openapi3::Spec) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+        for (path, item) in oas.paths.iter_mut() {
+            for (method, op) in Self::operations_mut(item) {
+                let location = format!("#/paths/{}/{method}", escape_json_pointer(path));
+                let mut contents = vec![];
+                if let Some(ObjectOrReference::Object(body)) = &mut op.request_body {
+                    for (media_type, content) in body.content.iter_mut() {
+                        let location = format!(
+                            "{location}/requestBody/content/{}/schema",
+                            escape_json_pointer(media_type)
+                        );
+                        contents.push((location, content));
+                    }
+                }
+                for (code, response) in op.responses.iter_mut() {
+                    for (media_type, content) in response.content.iter_mut().flatten() {
+                        let location = format!(
+                            "{location}/responses/{code}/content/{}/schema",
+                            escape_json_pointer(media_type)
+                        );
+                        contents.push((location, content));
+                    }
+                }
+                for (location, content) in contents {
+                    if let Some(ObjectOrReference::Object(schema)) = &mut content.schema {
+                        Self::describe_null_only_schema(schema, &location, &mut diagnostics);
+                    }
+                }
+            }
         }
+        diagnostics
     }
-}
 
-fn extract_description(description: &Option<postman::DescriptionUnion>) -> Option<String> {
-    match description {
-        Some(d) => match d {
-            postman::DescriptionUnion::String(s) => Some(s.to_string()),
-            postman::DescriptionUnion::Description(desc) => {
-                desc.content.as_ref().map(|c| c.to_string())
+    fn describe_null_only_schema(
+        schema: &mut openapi3::Schema,
+        location: &str,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        if is_null_schema(schema) {
+            *schema = openapi3::Schema {
+                description: Some(
+                    "The type could not be inferred because the value was `null` in every example."
+                        .to_string(),
+                ),
+                ..openapi3::Schema::default()
+            };
+            diagnostics.push(Diagnostic {
+                location: Some(location.to_string()),
+                message: "the value was null in every example, so its type could not be inferred"
+                    .to_string(),
+            });
+            return;
+        }
+        for (key, property) in schema.properties.iter_mut().flatten() {
+            let location = format!("{location}/properties/{}", escape_json_pointer(key));
+            Self::describe_null_only_schema(property, &location, diagnostics);
+        }
+        if let Some(ObjectOrReference::Object(values)) = &mut schema.additional_properties {
+            let location = format!("{location}/additionalProperties");
+            Self::describe_null_only_schema(values, &location, diagnostics);
+        }
+        if let Some(items) = &mut schema.items {
+            Self::describe_null_only_schema(items, &format!("{location}/items"), diagnostics);
+        }
+    }
+
+    /// Lists the strings observed in the examples of each body as the `enum` of the property
+    /// they were found in, when only a few distinct values were seen and at least one repeats.
+    fn infer_enums(oas: &mut openapi3::Spec, inference: &EnumInference) {
+        for item in oas.paths.values_mut() {
+            // Path parameters collect every value they were given while transforming.
+            for param in item.parameters.iter_mut().flatten() {
+                if let ObjectOrReference::Object(Parameter {
+                    schema: Some(schema),
+                    ..
+                }) = param
+                {
+                    if let Some(observed) = schema.enum_values.take() {
+                        let values = observed
+                            .iter()
+                            .map(|value| serde_json::Value::String(value.clone()))
+                            .collect::<Vec<_>>();
+                        Self::infer_enum_values(
+                            schema,
+                            &values.iter().collect::<Vec<_>>(),
+                            inference,
+                        );
+                    }
+                }
             }
-        },
-        None => None,
+            for (_, op) in Self::operations_mut(item) {
+                let mut contents = vec![];
+                if let Some(ObjectOrReference::Object(body)) = &mut op.request_body {
+                    contents.extend(body.content.values_mut());
+                }
+                for response in op.responses.values_mut() {
+                    contents.extend(response.content.iter_mut().flat_map(|c| c.values_mut()));
+                }
+                for content in contents {
+                    let values = match &content.examples {
+                        Some(openapi3::MediaTypeExample::Example { example }) => vec![example],
+                        Some(openapi3::MediaTypeExample::Examples { examples }) => examples
+                            .values()
+                            .filter_map(|example| match example {
+                                ObjectOrReference::Object(example) => example.value.as_ref(),
+                                ObjectOrReference::Ref { .. } => None,
+                            })
+                            .collect(),
+                        None => vec![],
+                    };
+                    if let Some(ObjectOrReference::Object(schema)) = &mut content.schema {
+                        Self::infer_enum_values(schema, &values, inference);
+                    }
+                }
+            }
+        }
     }
-}
 
-#[cfg(not(target_arch = "wasm32"))]
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use openapi::v3_0::{MediaTypeExample, ObjectOrReference, Parameter, Schema};
-    use openapi::OpenApi;
-    use postman::Spec;
+    fn infer_enum_values(
+        schema: &mut openapi3::Schema,
+        values: &[&serde_json::Value],
+        inference: &EnumInference,
+    ) {
+        match schema.schema_type.as_deref() {
+            Some("object") => {
+                for (key, property) in schema.properties.iter_mut().flatten() {
+                    let values = values
+                        .iter()
+                        .filter_map(|value| value.get(key))
+                        .collect::<Vec<_>>();
+                    Self::infer_enum_values(property, &values, inference);
+                }
+                if let Some(ObjectOrReference::Object(values_schema)) =
+                    &mut schema.additional_properties
+                {
+                    let values = values
+                        .iter()
+                        .filter_map(|value| value.as_object())
+                        .flat_map(|entries| entries.values())
+                        .collect::<Vec<_>>();
+                    Self::infer_enum_values(values_schema, &values, inference);
+                }
+            }
+            Some("array") => {
+                if let Some(items) = &mut schema.items {
+                    let values = values
+                        .iter()
+                        .filter_map(|value| value.as_array())
+                        .flatten()
+                        .collect::<Vec<_>>();
+                    Self::infer_enum_values(items, &values, inference);
+                }
+            }
+            // Formatted strings such as dates and identifiers are unique by nature.
+            Some("string") if schema.format.is_none() => {
+                let observed = values
+                    .iter()
+                    .filter_map(|value| value.as_str())
+                    .collect::<Vec<_>>();
+                let distinct = observed.iter().copied().collect::<IndexSet<_>>();
+                if observed.len() >= inference.min_occurrences
+                    && distinct.len() <= inference.max_values
+                    && distinct.len() < observed.len()
+                {
+                    schema.enum_values = Some(distinct.into_iter().map(String::from).collect());
+                }
+            }
+            _ => {}
+        }
+    }
 
-    #[test]
-    fn test_extract_description() {
-        let description = Some(postman::DescriptionUnion::String("test".to_string()));
-        assert_eq!(extract_description(&description), Some("test".to_string()));
+    /// Records the object schemas found in a schema, along with the folder and the resource of
+    /// the operation it belongs to. Bodies, and the items and values of bodies, are named after
+    /// the operation.
+    fn collect_schema_usages(
+        schema: &openapi3::Schema,
+        name: &str,
+        context: (&Option<String>, &Option<String>),
+        is_body: bool,
+        usages: &mut IndexMap<String, SchemaUsage>,
+    ) {
+        let (folder, resource) = context;
+        if let Some(fingerprint) = schema_fingerprint(schema) {
+            let usage = usages.entry(fingerprint).or_default();
+            usage.count += 1;
+            usage.folders.push(folder.clone());
+            if is_body {
+                usage.body_names.push(name.to_string());
+                usage.resources.push(resource.clone());
+            } else if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                usage.property_names.push(name.to_string());
+            }
+        }
+        for (key, property) in schema.properties.iter().flatten() {
+            let property_name = key
+                .to_case(Case::Pascal)
+                .chars()
+                .filter(char::is_ascii_alphanumeric)
+                .collect::<String>();
+            Self::collect_schema_usages(property, &property_name, context, false, usages);
+        }
+        if let Some(ObjectOrReference::Object(values)) = &schema.additional_properties {
+            let value_name = match singularize(name) {
+                singular if singular != name && !is_body => singular,
+                _ => format!("{name}Value"),
+            };
+            Self::collect_schema_usages(values, &value_name, context, is_body, usages);
+        }
+        if let Some(items) = &schema.items {
+            let item_name = match singularize(name) {
+                singular if singular != name && !is_body => singular,
+                _ => format!("{name}Item"),
+            };
+            Self::collect_schema_usages(items, &item_name, context, is_body, usages);
+        }
+    }
 
-        let description = Some(postman::DescriptionUnion::Description(
-            postman::Description {
-                content: Some("test".to_string()),
-                ..postman::Description::default()
-            },
-        ));
-        assert_eq!(extract_description(&description), Some("test".to_string()));
+    fn replace_shared_schemas(
+        schema: &mut openapi3::Schema,
+        names: &BTreeMap<String, String>,
+        schemas: &mut BTreeMap<String, ObjectOrReference<openapi3::Schema>>,
+    ) {
+        let name = schema_fingerprint(schema).and_then(|fingerprint| names.get(&fingerprint));
+        for property in schema.properties.iter_mut().flat_map(|p| p.values_mut()) {
+            Self::replace_shared_schemas(property, names, schemas);
+        }
+        if let Some(items) = &mut schema.items {
+            Self::replace_shared_schemas(items, names, schemas);
+        }
+        if let Some(ObjectOrReference::Object(values)) = &mut schema.additional_properties {
+            Self::replace_shared_schemas(values, names, schemas);
+        }
+        if let Some(name) = name {
+            let mut shared = std::mem::replace(
+                schema,
+                openapi3::Schema {
+                    ref_path: Some(format!("#/components/schemas/{name}")),
+                    ..openapi3::Schema::default()
+                },
+            );
+            // The examples of one copy don't describe the others.
+            strip_examples(&mut shared);
+            schemas
+                .entry(name.clone())
+                .or_insert(ObjectOrReference::Object(shared));
+        }
+    }
 
-        let description = None;
-        assert_eq!(extract_description(&description), None);
+    fn operations(item: &openapi3::PathItem) -> impl Iterator<Item = &openapi3::Operation> {
+        [
+            &item.get,
+            &item.put,
+            &item.post,
+            &item.delete,
+            &item.options,
+            &item.head,
+            &item.patch,
+            &item.trace,
+        ]
+        .into_iter()
+        .flatten()
     }
 
-    #[test]
-    fn test_generate_path_parameters() {
-        let empty_map = BTreeMap::<_, _>::new();
-        let transpiler = Transpiler::new(&empty_map);
-        let postman_variables = Some(vec![postman::Variable {
-            key: Some("test".to_string()),
-            value: Some(serde_json::Value::String("test_value".to_string())),
-            description: None,
-            ..postman::Variable::default()
-        }]);
-        let path_params = ["/test/".to_string(), "{{test_value}}".to_string()];
-        let params = transpiler.generate_path_parameters(&path_params, &postman_variables);
-        assert_eq!(params.unwrap().len(), 1);
+    fn operations_mut(
+        item: &mut openapi3::PathItem,
+    ) -> impl Iterator<Item = (&'static str, &mut openapi3::Operation)> {
+        Self::operation_slots_mut(item)
+            .into_iter()
+            .filter_map(|(method, op)| op.as_mut().map(|op| (method, op)))
     }
 
-    #[test]
+    fn operation_slots_mut(
+        item: &mut openapi3::PathItem,
+    ) -> [(&'static str, &mut Option<openapi3::Operation>); 8] {
+        [
+            ("get", &mut item.get),
+            ("put", &mut item.put),
+            ("post", &mut item.post),
+            ("delete", &mut item.delete),
+            ("options", &mut item.options),
+            ("head", &mut item.head),
+            ("patch", &mut item.patch),
+            ("trace", &mut item.trace),
+        ]
+    }
+
+    /// Adds parameters to a list, combining those with the same name and location: the first
+    /// description is kept and the schemas are merged. When `collect_enums` is set, the distinct
+    /// plain strings a parameter takes are listed as an `enum`.
+    fn merge_parameters(
+        parameters: &mut Option<Vec<ObjectOrReference<Parameter>>>,
+        new_parameters: Vec<ObjectOrReference<Parameter>>,
+        collect_enums: bool,
+    ) {
+        if new_parameters.is_empty() {
+            return;
+        }
+        let parameters = parameters.get_or_insert_with(Vec::new);
+        for new in new_parameters {
+            let found = parameters.iter_mut().find_map(|p| match (p, &new) {
+                (ObjectOrReference::Object(p), ObjectOrReference::Object(new))
+                    if p.location == new.location && p.name == new.name =>
+                {
+                    Some(p)
+                }
+                _ => None,
+            });
+            let (Some(param), ObjectOrReference::Object(new)) = (found, &new) else {
+                parameters.push(new);
+                continue;
+            };
+            // An object sent as bracketed keys can't be merged with a plain value of the same
+            // name, so the parameter described first is kept.
+            let is_deep_object =
+                |p: &Parameter| p.style == Some(openapi3::ParameterStyle::DeepObject);
+            if is_deep_object(param) != is_deep_object(new) {
+                continue;
+            }
+            if param.description.is_none() {
+                param.description = new.description.clone();
+            }
+            param.required =
+                (param.required == Some(true) && new.required == Some(true)).then_some(true);
+            if param.style.is_none() {
+                param.style = new.style.clone();
+                param.explode = new.explode;
+            }
+            param.schema = match (param.schema.take(), &new.schema) {
+                (Some(schema), Some(new_schema)) => Some(Self::merge_parameter_schemas(
+                    schema,
+                    new_schema,
+                    collect_enums,
+                )),
+                (schema, new_schema) => schema.or_else(|| new_schema.clone()),
+            };
+        }
+    }
+
+    /// Merges the query and header parameters of a request into its operation. A parameter stays
+    /// required only while every request to the operation sends it enabled, so when earlier
+    /// requests already described the operation, parameters missing from either side become
+    /// optional.
+    fn merge_request_parameters(
+        parameters: &mut Option<Vec<ObjectOrReference<Parameter>>>,
+        mut new_parameters: Vec<ObjectOrReference<Parameter>>,
+        is_merge: bool,
+    ) {
+        fn keys(params: &[ObjectOrReference<Parameter>]) -> BTreeSet<(String, String)> {
+            params
+                .iter()
+                .filter_map(|p| match p {
+                    ObjectOrReference::Object(p) => Some((p.location.clone(), p.name.clone())),
+                    _ => None,
+                })
+                .collect()
+        }
+        fn make_optional(
+            params: &mut [ObjectOrReference<Parameter>],
+            except: &BTreeSet<(String, String)>,
+        ) {
+            for p in params {
+                if let ObjectOrReference::Object(p) = p {
+                    if !except.contains(&(p.location.clone(), p.name.clone())) {
+                        p.required = None;
+                    }
+                }
+            }
+        }
+
+        if is_merge {
+            let existing = keys(parameters.as_deref().unwrap_or_default());
+            let sent = keys(&new_parameters);
+            make_optional(parameters.as_deref_mut().unwrap_or_default(), &sent);
+            make_optional(&mut new_parameters, &existing);
+        }
+        Self::merge_parameters(parameters, new_parameters, false);
+    }
+
+    /// Merges the schemas of a parameter inferred from two requests. A schema without an example
+    /// gives way to one with an example, a single value joins the items of an array, and values
+    /// that disagree on their type are described as strings.
+    fn merge_parameter_schemas(
+        mut schema: openapi3::Schema,
+        new: &openapi3::Schema,
+        collect_enums: bool,
+    ) -> openapi3::Schema {
+        let has_example = |schema: &openapi3::Schema| {
+            schema
+                .example
+                .as_ref()
+                .is_some_and(|e| e.as_str() != Some(""))
+        };
+        if !has_example(new) {
+            return schema;
+        }
+        if !has_example(&schema) {
+            return new.clone();
+        }
+
+        let is_array = |s: &openapi3::Schema| s.schema_type.as_deref() == Some("array");
+        if is_array(&schema) || is_array(new) {
+            let (mut array, new_items) = match (is_array(&schema), is_array(new)) {
+                (true, true) => (schema, new.items.as_deref().cloned()),
+                (true, false) => (schema, Some(new.clone())),
+                _ => (new.clone(), Some(schema)),
+            };
+            array.items = match (array.items.take(), new_items) {
+                (Some(items), Some(new_items)) => Some(Box::new(Self::merge_parameter_schemas(
+                    *items, &new_items, false,
+                ))),
+                (items, new_items) => items.or(new_items.map(Box::new)),
+            };
+            return array;
+        }
+
+        let is_numeric =
+            |s: &openapi3::Schema| matches!(s.schema_type.as_deref(), Some("integer" | "number"));
+        if schema.schema_type != new.schema_type && !(is_numeric(&schema) && is_numeric(new)) {
+            schema.schema_type = Some("string".to_string());
+            schema.format = None;
+            schema.enum_values = None;
+            return schema;
+        }
+
+        // Every value observed is collected, repeats included, so that `infer_enums` can apply
+        // the same thresholds as for bodies.
+        let is_plain_string =
+            |s: &openapi3::Schema| s.schema_type.as_deref() == Some("string") && s.format.is_none();
+        let values =
+            (collect_enums && is_plain_string(&schema) && is_plain_string(new)).then(|| {
+                let mut values = schema.enum_values.clone().unwrap_or_else(|| {
+                    schema
+                        .example
+                        .iter()
+                        .filter_map(|e| e.as_str())
+                        .map(String::from)
+                        .collect()
+                });
+                values.extend(
+                    new.example
+                        .as_ref()
+                        .and_then(|e| e.as_str())
+                        .map(String::from),
+                );
+                values
+            });
+        let mut merged = Self::merge_schemas(schema, new);
+        if values.is_some() {
+            merged.enum_values = values;
+        }
+        merged
+    }
+
+    /// Moves the parameters shared by every operation of a path to the path item.
+    fn hoist_shared_parameters(oas: &mut openapi3::Spec) {
+        for item in oas.paths.values_mut() {
+            if Self::operations(item).count() < 2 {
+                continue;
+            }
+            // Parameters only move when every operation agrees on whether they are required.
+            let key = |p: &ObjectOrReference<Parameter>| match p {
+                ObjectOrReference::Object(p) => {
+                    Some((p.name.clone(), p.location.clone(), p.required))
+                }
+                _ => None,
+            };
+            let shared = Self::operations(item)
+                .map(|op| {
+                    op.parameters
+                        .iter()
+                        .flatten()
+                        .filter_map(key)
+                        .collect::<BTreeSet<_>>()
+                })
+                .reduce(|shared, keys| shared.intersection(&keys).cloned().collect())
+                .unwrap_or_default();
+            if shared.is_empty() {
+                continue;
+            }
+
+            let mut hoisted = Vec::new();
+            for (_, op) in Self::operations_mut(item) {
+                let params = op.parameters.take().unwrap_or_default();
+                let (moved, kept): (Vec<_>, Vec<_>) = params
+                    .into_iter()
+                    .partition(|p| key(p).is_some_and(|k| shared.contains(&k)));
+                hoisted.extend(moved);
+                op.parameters = Some(kept).filter(|kept| !kept.is_empty());
+            }
+            Self::merge_parameters(&mut item.parameters, hoisted, false);
+        }
+    }
+
+    /// Describes a parameter value, resolving variables in the example before inferring its
+    /// format.
+    fn generate_string_schema(&self, value: Option<&str>) -> openapi3::Schema {
+        let example = value.map(|value| self.resolve_variables(value, VAR_REPLACE_CREDITS));
+        openapi3::Schema {
+            schema_type: Some("string".to_string()),
+            format: example.as_deref().and_then(infer_string_format),
+            example: example.map(serde_json::Value::String),
+            ..openapi3::Schema::default()
+        }
+    }
+
+    /// Describes a parameter value, typing integers, decimal numbers and booleans and otherwise
+    /// inferring the format of the string.
+    fn generate_scalar_schema(&self, value: Option<&str>) -> openapi3::Schema {
+        let resolved = value.map(|value| self.resolve_variables(value, VAR_REPLACE_CREDITS));
+        let typed = match resolved.as_deref() {
+            // Leading zeros are significant, so `007` stays a string.
+            Some(v) if v.parse::<i64>().is_ok_and(|i| i.to_string() == v) => {
+                v.parse::<i64>().ok().map(serde_json::Value::from)
+            }
+            Some(v) if DECIMAL_RE.is_match(v) => v
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(serde_json::Value::Number),
+            Some(v @ ("true" | "false")) => Some(serde_json::Value::Bool(v == "true")),
+            _ => None,
+        };
+        typed
+            .as_ref()
+            .and_then(Self::generate_schema)
+            .unwrap_or_else(|| self.generate_string_schema(value))
+    }
+
+    /// Describes the values given for a parameter as an array of their merged schemas.
+    fn generate_array_schema(&self, values: &[Option<&str>]) -> openapi3::Schema {
+        let items = values
+            .iter()
+            .map(|value| self.generate_scalar_schema(*value))
+            .collect::<Vec<_>>();
+        let example = items
+            .iter()
+            .filter_map(|item| item.example.clone())
+            .collect::<Vec<_>>();
+        let items = items
+            .into_iter()
+            .reduce(|merged, item| Self::merge_parameter_schemas(merged, &item, false));
+        openapi3::Schema {
+            schema_type: Some("array".to_string()),
+            items: items.map(Box::new),
+            example: Some(serde_json::Value::Array(example)),
+            ..openapi3::Schema::default()
+        }
+    }
+
+    /// Describes a path parameter from its Postman variable, using the variable's declared type
+    /// when there is one, and otherwise inferring it from its value.
+    fn generate_path_parameter_schema(
+        &self,
+        variable: Option<&postman::Variable>,
+    ) -> openapi3::Schema {
+        let value = variable
+            .and_then(|v| v.value.as_ref())
+            .and_then(|value| match value {
+                serde_json::Value::String(s) => {
+                    Some(self.resolve_variables(s, VAR_REPLACE_CREDITS))
+                }
+                serde_json::Value::Null => None,
+                value => Some(value.to_string()),
+            })
+            .filter(|value| !value.is_empty());
+        let typed_schema = |schema_type: &str, example: Option<serde_json::Value>| {
+            Self::generate_schema(example.as_ref()?).filter(|s| {
+                s.schema_type.as_deref() == Some(schema_type)
+                    || (schema_type == "number" && s.schema_type.as_deref() == Some("integer"))
+            })
+        };
+
+        match (
+            variable.and_then(|v| v.variable_type.as_ref()),
+            value.as_deref(),
+        ) {
+            (Some(postman::VariableType::Boolean), value) => {
+                let example = value.and_then(|v| v.parse::<bool>().ok());
+                typed_schema("boolean", example.map(serde_json::Value::Bool)).unwrap_or_else(|| {
+                    openapi3::Schema {
+                        schema_type: Some("boolean".to_string()),
+                        ..openapi3::Schema::default()
+                    }
+                })
+            }
+            (Some(postman::VariableType::Number), value) => {
+                let example = value.and_then(|v| v.parse::<serde_json::Number>().ok());
+                typed_schema("number", example.map(serde_json::Value::Number)).unwrap_or_else(
+                    || openapi3::Schema {
+                        schema_type: Some("number".to_string()),
+                        ..openapi3::Schema::default()
+                    },
+                )
+            }
+            (Some(postman::VariableType::String), value) => self.generate_string_schema(value),
+            (_, value) => self.generate_scalar_schema(value),
+        }
+    }
+
+    fn generate_path_parameters(
+        &self,
+        resolved_segments: &[String],
+        postman_variables: &Option<Vec<postman::Variable>>,
+    ) -> Option<Vec<openapi3::ObjectOrReference<openapi3::Parameter>>> {
+        let params: Vec<openapi3::ObjectOrReference<openapi3::Parameter>> = resolved_segments
+            .iter()
+            .flat_map(|segment| {
+                URI_TEMPLATE_VARIABLE_RE
+                    .captures_iter(segment.as_str())
+                    .map(|capture| {
+                        let var = capture.get(1).unwrap().as_str();
+                        let mut param = Parameter {
+                            name: var.to_owned(),
+                            location: "path".to_owned(),
+                            required: Some(true),
+                            ..Parameter::default()
+                        };
+
+                        let variable = postman_variables.iter().flatten().find(|p| match &p.key {
+                            Some(k) => k == var,
+                            _ => false,
+                        });
+                        if let Some(p) = variable {
+                            param.description = extract_description(&p.description);
+                        }
+                        param.schema = Some(self.generate_path_parameter_schema(variable));
+                        openapi3::ObjectOrReference::Object(param)
+                    })
+            })
+            .collect();
+
+        if !params.is_empty() {
+            Some(params)
+        } else {
+            None
+        }
+    }
+
+    /// Describes bracketed keys such as `filter[owner][id]` as a single `deepObject` parameter,
+    /// each key adding a described property to its schema. A trailing `[]` or a repeated key
+    /// makes the property an array.
+    fn generate_deep_object_parameter(
+        &self,
+        name: &str,
+        entries: &[(Vec<&str>, &postman::QueryParam)],
+    ) -> Parameter {
+        let mut leaves = IndexMap::<&[&str], Vec<&postman::QueryParam>>::new();
+        for (path, qp) in entries {
+            leaves.entry(path.as_slice()).or_default().push(qp);
+        }
+
+        let mut schema = openapi3::Schema {
+            schema_type: Some("object".to_string()),
+            ..openapi3::Schema::default()
+        };
+        let mut example = serde_json::Map::new();
+        for (path, qps) in leaves {
+            let (path, is_array) = match path.split_last() {
+                Some((&"", parents)) => (parents, true),
+                _ => (path, false),
+            };
+            let values = qps.iter().map(|qp| qp.value.as_deref()).collect::<Vec<_>>();
+            let mut leaf = if is_array || values.len() > 1 {
+                self.generate_array_schema(&values)
+            } else {
+                self.generate_scalar_schema(values[0])
+            };
+            leaf.description = qps
+                .iter()
+                .find_map(|qp| extract_description(&qp.description));
+            insert_deep_object_property(&mut schema, &mut example, path, leaf);
+        }
+        schema.example = Some(serde_json::Value::Object(example))
+            .filter(|example| example.as_object().is_some_and(|e| !e.is_empty()));
+
+        Parameter {
+            name: name.to_owned(),
+            location: "query".to_owned(),
+            required: entries
+                .iter()
+                .any(|(_, qp)| qp.disabled != Some(true))
+                .then_some(true),
+            schema: Some(schema),
+            style: Some(openapi3::ParameterStyle::DeepObject),
+            explode: Some(true),
+            ..Parameter::default()
+        }
+    }
+
+    /// Reads the string values of a query parameter as comma-separated lists when requests give
+    /// it lists of different lengths, such as `fields=id,name` and `fields=id,name,email`, or
+    /// when the parameter was already read as a list.
+    fn infer_comma_separated_lists(
+        &self,
+        parameters: &mut Option<Vec<openapi3::ObjectOrReference<openapi3::Parameter>>>,
+        new_parameters: &mut [openapi3::ObjectOrReference<openapi3::Parameter>],
+    ) {
+        let item_count = |param: &Parameter| {
+            let schema = param.schema.as_ref()?;
+            let example = schema.example.as_ref()?.as_str()?;
+            (param.style.is_none() && schema.schema_type.as_deref() == Some("string"))
+                .then(|| comma_separated_items(example).map(|items| items.len()))
+                .flatten()
+        };
+        let is_list = |param: &Parameter| {
+            param.explode == Some(false)
+                && param.schema.as_ref().and_then(|s| s.schema_type.as_deref()) == Some("array")
+        };
+
+        for new in new_parameters.iter_mut() {
+            let ObjectOrReference::Object(new) = new else {
+                continue;
+            };
+            let Some(count) = item_count(new).filter(|_| new.location == "query") else {
+                continue;
+            };
+            let existing = parameters.iter_mut().flatten().find_map(|p| match p {
+                ObjectOrReference::Object(p)
+                    if p.name == new.name && p.location == new.location =>
+                {
+                    Some(p)
+                }
+                _ => None,
+            });
+            let Some(existing) = existing else {
+                continue;
+            };
+            if is_list(existing) {
+                self.make_comma_separated_list(new);
+            } else if item_count(existing).is_some_and(|c| c != count) {
+                self.make_comma_separated_list(existing);
+                self.make_comma_separated_list(new);
+            }
+        }
+    }
+
+    /// Turns a parameter whose example is a comma-separated list into an array that is not
+    /// exploded.
+    fn make_comma_separated_list(&self, param: &mut Parameter) {
+        let example = param.schema.as_ref().and_then(|s| s.example.as_ref());
+        let Some(items) = example
+            .and_then(|e| e.as_str())
+            .and_then(comma_separated_items)
+            .map(|items| items.into_iter().map(String::from).collect::<Vec<_>>())
+        else {
+            return;
+        };
+        let items = items
+            .iter()
+            .map(|item| Some(item.as_str()))
+            .collect::<Vec<_>>();
+        param.schema = Some(self.generate_array_schema(&items));
+        param.style = Some(openapi3::ParameterStyle::Form);
+        param.explode = Some(false);
+    }
+
+    /// Describes the query parameters of a request. Keys that enabled entries repeat or that end
+    /// in `[]` are arrays of exploded values, unless a key ending in `[]` has a single value made
+    /// of comma-separated items, which is an array that is not exploded. Bracketed keys such as
+    /// `filter[status]` make up object parameters. Parameters that are only ever disabled are
+    /// optional.
+    fn generate_query_parameters(
+        &self,
+        query_params: &[postman::QueryParam],
+    ) -> Option<Vec<openapi3::ObjectOrReference<openapi3::Parameter>>> {
+        // Bracketed keys such as `filter[status]` are grouped under their name, apart from
+        // plain keys. When the name is also sent as a plain key, as in `filter=open`, an object
+        // can't describe both, so the bracketed keys are kept as they are.
+        let plain_keys = query_params
+            .iter()
+            .filter_map(|qp| qp.key.as_deref())
+            .filter(|key| deep_object_key(key).is_none())
+            .collect::<BTreeSet<_>>();
+        let mut grouped = IndexMap::<(&str, bool), Vec<(Vec<&str>, &postman::QueryParam)>>::new();
+        for qp in query_params {
+            if let Some(key) = &qp.key {
+                match deep_object_key(key).filter(|(name, _)| !plain_keys.contains(name)) {
+                    Some((name, path)) => grouped.entry((name, true)).or_default().push((path, qp)),
+                    None => grouped.entry((key, false)).or_default().push((vec![], qp)),
+                }
+            }
+        }
+
+        let params = grouped
+            .into_iter()
+            .map(|((key, is_deep_object), entries)| {
+                if is_deep_object {
+                    let param = self.generate_deep_object_parameter(key, &entries);
+                    return openapi3::ObjectOrReference::Object(param);
+                }
+                let qps = entries.iter().map(|(_, qp)| *qp).collect::<Vec<_>>();
+                // Disabled entries document alternatives to the enabled ones, so only enabled
+                // entries make a repeated key. Their values follow those of the enabled entries.
+                let (enabled, disabled): (Vec<&postman::QueryParam>, Vec<_>) =
+                    qps.iter().partition(|qp| qp.disabled != Some(true));
+                let values = enabled
+                    .iter()
+                    .chain(&disabled)
+                    .map(|qp| qp.value.as_deref())
+                    .collect::<Vec<_>>();
+                // Commas are as likely to be part of a value, as in `Smith,John`, so a single
+                // value is only read as a list when the key says it is an array.
+                let list = values[0]
+                    .filter(|_| key.ends_with("[]") && enabled.len() <= 1)
+                    .map(|value| self.resolve_variables(value, VAR_REPLACE_CREDITS))
+                    .filter(|value| comma_separated_items(value).is_some());
+                let (schema, explode) = if let Some(list) = &list {
+                    let items = list.split(',').map(Some).collect::<Vec<_>>();
+                    (self.generate_array_schema(&items), Some(false))
+                } else if enabled.len() > 1 || key.ends_with("[]") {
+                    (
+                        self.generate_array_schema(&values[..enabled.len().max(1)]),
+                        Some(true),
+                    )
+                } else {
+                    let schema = values
+                        .iter()
+                        .map(|value| self.generate_scalar_schema(*value))
+                        .reduce(|merged, schema| {
+                            Self::merge_parameter_schemas(merged, &schema, false)
+                        })
+                        .unwrap();
+                    (schema, None)
+                };
+                let param = Parameter {
+                    name: key.to_owned(),
+                    description: qps
+                        .iter()
+                        .find_map(|qp| extract_description(&qp.description)),
+                    location: "query".to_owned(),
+                    required: (!enabled.is_empty()).then_some(true),
+                    schema: Some(schema),
+                    style: explode.map(|_| openapi3::ParameterStyle::Form),
+                    explode,
+                    ..Parameter::default()
+                };
+                openapi3::ObjectOrReference::Object(param)
+            })
+            .collect::<Vec<openapi3::ObjectOrReference<openapi3::Parameter>>>();
+
+        if !params.is_empty() {
+            Some(params)
+        } else {
+            None
+        }
+    }
+}
+
+/// Removes the examples of a schema and of the schemas it contains.
+fn strip_examples(schema: &mut openapi3::Schema) {
+    schema.example = None;
+    for property in schema.properties.iter_mut().flat_map(|p| p.values_mut()) {
+        strip_examples(property);
+    }
+    if let Some(items) = &mut schema.items {
+        strip_examples(items);
+    }
+    if let Some(ObjectOrReference::Object(values)) = &mut schema.additional_properties {
+        strip_examples(values);
+    }
+}
+
+/// The items of a value made of comma-separated items, such as `name,email`.
+fn comma_separated_items(value: &str) -> Option<Vec<&str>> {
+    let items = value.split(',').collect::<Vec<_>>();
+    (items.len() > 1
+        && !value.contains(char::is_whitespace)
+        && items.iter().all(|item| !item.is_empty()))
+    .then_some(items)
+}
+
+/// Identifies object schemas by structure so that copies inferred from different examples
+/// compare equal. Returns `None` for anything that isn't an object with properties.
+fn schema_fingerprint(schema: &openapi3::Schema) -> Option<String> {
+    if schema.schema_type.as_deref() != Some("object")
+        || schema.properties.as_ref().is_none_or(|p| p.is_empty())
+    {
+        return None;
+    }
+    let mut schema = schema.clone();
+    strip_examples(&mut schema);
+    serde_json::to_string(&schema).ok()
+}
+
+/// Whether a security scheme is an API key sent in a cookie, as session cookies are described.
+fn is_cookie_scheme(components: &Option<openapi3::Components>, name: &str) -> bool {
+    let scheme = components
+        .as_ref()
+        .and_then(|components| components.security_schemes.as_ref())
+        .and_then(|schemes| schemes.get(name));
+    matches!(
+        scheme,
+        Some(ObjectOrReference::Object(openapi3::SecurityScheme::ApiKey { location, .. }))
+            if location == "cookie"
+    )
+}
+
+/// Whether a cookie is named like a session or authentication token, leaving out the CSRF
+/// tokens that are often sent alongside.
+fn is_session_cookie(name: &str) -> bool {
+    let name = name.to_lowercase();
+    SESSION_COOKIE_RE.is_match(&name) && !CSRF_COOKIE_RE.is_match(&name)
+}
+
+/// Reads the cookie set by a `Set-Cookie` header, such as
+/// `sessionid=38afes7a8; Path=/; HttpOnly`.
+fn parse_set_cookie(header: &str) -> Option<postman::Cookie> {
+    let mut parts = header.split(';');
+    let (name, value) = parts.next()?.split_once('=')?;
+    let name = name.trim();
+    if name.is_empty() {
+        return None;
+    }
+
+    let mut cookie = postman::Cookie {
+        name: Some(name.to_string()),
+        value: Some(value.trim().to_string()),
+        ..postman::Cookie::default()
+    };
+    for attribute in parts {
+        let (key, value) = match attribute.split_once('=') {
+            Some((key, value)) => (key.trim(), Some(value.trim().to_string())),
+            None => (attribute.trim(), None),
+        };
+        match key.to_lowercase().as_str() {
+            "domain" => cookie.domain = value,
+            "path" => cookie.path = value,
+            "expires" => cookie.expires = value,
+            "max-age" => cookie.max_age = value,
+            "httponly" => cookie.http_only = Some(true),
+            "secure" => cookie.secure = Some(true),
+            _ => {}
+        }
+    }
+    Some(cookie)
+}
+
+/// Describes the cookies set by a saved response as a `Set-Cookie` header. The description lists
+/// each cookie with a summary of its attributes, and the first cookie is the example.
+fn generate_set_cookie_header(cookies: &[postman::Cookie]) -> Option<openapi3::Header> {
+    let mut names = IndexSet::new();
+    let mut lines = Vec::new();
+    for cookie in cookies {
+        let Some(name) = cookie_name(cookie) else {
+            continue;
+        };
+        if names.insert(name) {
+            lines.push(format!("- {}", describe_cookie(name, cookie)));
+        }
+    }
+    let first = *names.first()?;
+    let cookie = cookies
+        .iter()
+        .find(|cookie| cookie_name(cookie) == Some(first))?;
+
+    let mut example = format!("{first}={}", cookie.value.as_deref().unwrap_or_default());
+    for (attribute, value) in [
+        ("Domain", cookie.domain.as_deref()),
+        ("Path", cookie.path.as_deref()),
+        ("Expires", cookie_expiry(cookie)),
+        ("Max-Age", cookie.max_age.as_deref()),
+    ] {
+        if let Some(value) = value {
+            example.push_str(&format!("; {attribute}={value}"));
+        }
+    }
+    if cookie.http_only == Some(true) {
+        example.push_str("; HttpOnly");
+    }
+    if cookie.secure == Some(true) {
+        example.push_str("; Secure");
+    }
+
+    Some(openapi3::Header {
+        description: Some(lines.join("\n")),
+        schema: Some(openapi3::Schema {
+            schema_type: Some("string".to_string()),
+            example: Some(serde_json::Value::String(example)),
+            ..openapi3::Schema::default()
+        }),
+        ..openapi3::Header::default()
+    })
+}
+
+/// The name of a cookie, read from `key` when the cookie has no `name`.
+fn cookie_name(cookie: &postman::Cookie) -> Option<&str> {
+    cookie
+        .name
+        .as_deref()
+        .or(cookie.key.as_deref())
+        .filter(|name| !name.is_empty())
+}
+
+/// Summarizes a cookie and its attributes, such as
+/// `` `sessionid`: path `/`, HTTP only, secure ``.
+fn describe_cookie(name: &str, cookie: &postman::Cookie) -> String {
+    let expires = cookie_expiry(cookie);
+    let mut attributes = Vec::new();
+    if let Some(domain) = &cookie.domain {
+        attributes.push(format!("domain `{domain}`"));
+    }
+    if let Some(path) = &cookie.path {
+        attributes.push(format!("path `{path}`"));
+    }
+    if let Some(expires) = expires {
+        attributes.push(format!("expires {expires}"));
+    }
+    if let Some(max_age) = &cookie.max_age {
+        attributes.push(format!("max age {max_age} seconds"));
+    }
+    if cookie.session == Some(true) && expires.is_none() && cookie.max_age.is_none() {
+        attributes.push("session cookie".to_string());
+    }
+    if cookie.http_only == Some(true) {
+        attributes.push("HTTP only".to_string());
+    }
+    if cookie.secure == Some(true) {
+        attributes.push("secure".to_string());
+    }
+
+    if attributes.is_empty() {
+        format!("`{name}`")
+    } else {
+        format!("`{name}`: {}", attributes.join(", "))
+    }
+}
+
+/// When a cookie expires. Postman records cookies without an expiry date as expiring on an
+/// `Invalid Date`.
+fn cookie_expiry(cookie: &postman::Cookie) -> Option<&str> {
+    cookie
+        .expires
+        .as_deref()
+        .filter(|expires| *expires != "Invalid Date")
+}
+
+/// Whether the schema was inferred from a `null` value alone.
+fn is_null_schema(schema: &openapi3::Schema) -> bool {
+    schema.nullable == Some(true)
+        && schema.schema_type.is_none()
+        && schema.ref_path.is_none()
+        && schema.properties.is_none()
+        && schema.items.is_none()
+        && schema.additional_properties.is_none()
+        && schema.all_of.is_none()
+        && schema.one_of.is_none()
+        && schema.any_of.is_none()
+}
+
+/// The ISO 639-1 language codes, in order.
+static LANGUAGE_CODES: [&str; 184] = [
+    "aa", "ab", "ae", "af", "ak", "am", "an", "ar", "as", "av", "ay", "az", "ba", "be", "bg", "bh",
+    "bi", "bm", "bn", "bo", "br", "bs", "ca", "ce", "ch", "co", "cr", "cs", "cu", "cv", "cy", "da",
+    "de", "dv", "dz", "ee", "el", "en", "eo", "es", "et", "eu", "fa", "ff", "fi", "fj", "fo", "fr",
+    "fy", "ga", "gd", "gl", "gn", "gu", "gv", "ha", "he", "hi", "ho", "hr", "ht", "hu", "hy", "hz",
+    "ia", "id", "ie", "ig", "ii", "ik", "io", "is", "it", "iu", "ja", "jv", "ka", "kg", "ki", "kj",
+    "kk", "kl", "km", "kn", "ko", "kr", "ks", "ku", "kv", "kw", "ky", "la", "lb", "lg", "li", "ln",
+    "lo", "lt", "lu", "lv", "mg", "mh", "mi", "mk", "ml", "mn", "mr", "ms", "mt", "my", "na", "nb",
+    "nd", "ne", "ng", "nl", "nn", "no", "nr", "nv", "ny", "oc", "oj", "om", "or", "os", "pa", "pi",
+    "pl", "ps", "pt", "qu", "rm", "rn", "ro", "ru", "rw", "sa", "sc", "sd", "se", "sg", "si", "sk",
+    "sl", "sm", "sn", "so", "sq", "sr", "ss", "st", "su", "sv", "sw", "ta", "te", "tg", "th", "ti",
+    "tk", "tl", "tn", "to", "tr", "ts", "tt", "tw", "ty", "ug", "uk", "ur", "uz", "ve", "vi", "vo",
+    "wa", "wo", "xh", "yi", "yo", "za", "zh", "zu",
+];
+
+/// Classifies keys that identify entries of a dictionary rather than name properties. Locales
+/// must name a known language, such as `en` or `pt-BR`.
+fn dictionary_key_kind(key: &str) -> Option<&'static str> {
+    if INTEGER_KEY_RE.is_match(key) {
+        Some("integer")
+    } else if UUID_RE.is_match(key) {
+        Some("uuid")
+    } else if LOCALE_KEY_RE
+        .captures(key)
+        .is_some_and(|locale| LANGUAGE_CODES.binary_search(&&locale[1]).is_ok())
+    {
+        Some("locale")
+    } else {
+        None
+    }
+}
+
+/// Rewrites path segments that look like identifiers, or that vary between requests to the same
+/// path alongside such identifiers, as path parameters named after the preceding segment, such
+/// as `{userId}` after `users`. The literal values are added to the Postman path variables so
+/// they are kept as examples.
+fn templatize_path_ids(
+    segments: Vec<String>,
+    variables: &Option<Vec<postman::Variable>>,
+    varying: &VaryingSegments,
+) -> (Vec<String>, Option<Vec<postman::Variable>>) {
+    let mut names = segments
+        .iter()
+        .flat_map(|segment| path_parameter_names(segment))
+        .collect::<BTreeSet<_>>();
+    let mut variables = variables.clone();
+    let mut templated = Vec::with_capacity(segments.len());
+    for (i, segment) in segments.iter().enumerate() {
+        let is_id = !segment.is_empty()
+            && !segment.contains('{')
+            && (is_path_id(segment)
+                || varying
+                    .get(&segment_shape(&segments, i))
+                    .is_some_and(|values| {
+                        values.len() > 1 && values.iter().any(|v| is_path_id(v))
+                    }));
+        if !is_id {
+            templated.push(segment.clone());
+            continue;
+        }
+
+        let base = match i.checked_sub(1).map(|j| &segments[j]) {
+            Some(prev) if !prev.is_empty() && !prev.contains('{') && !is_path_id(prev) => {
+                format!("{}Id", singularize(prev).to_case(Case::Camel))
+            }
+            _ => "id".to_string(),
+        };
+        let mut name = base.clone();
+        let mut n = 1;
+        while names.contains(&name) {
+            n += 1;
+            name = format!("{base}{n}");
+        }
+        names.insert(name.clone());
+        variables
+            .get_or_insert_with(Vec::new)
+            .push(postman::Variable {
+                key: Some(name.clone()),
+                value: Some(serde_json::Value::String(segment.clone())),
+                ..postman::Variable::default()
+            });
+        templated.push(format!("{{{name}}}"));
+    }
+    (templated, variables)
+}
+
+/// Whether a literal path segment looks like a numeric, UUID, hex, slug or prefixed identifier.
+fn is_path_id(segment: &str) -> bool {
+    let has_digit = segment.chars().any(|c| c.is_ascii_digit());
+    INTEGER_KEY_RE.is_match(segment)
+        || UUID_RE.is_match(segment)
+        || (HEX_ID_RE.is_match(segment) && has_digit)
+        || (PREFIXED_ID_RE.is_match(segment) && has_digit)
+        || (SLUG_ID_RE.is_match(segment)
+            && segment
+                .split(['-', '_'])
+                .any(|part| INTEGER_KEY_RE.is_match(part)))
+}
+
+fn segment_shape(segments: &[String], index: usize) -> String {
+    segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| {
+            if i == index {
+                "*"
+            } else if segment.contains('{') || is_path_id(segment) {
+                "{}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn find_varying_segments(paths: &[Vec<String>]) -> VaryingSegments {
+    let mut varying = VaryingSegments::new();
+    for segments in paths {
+        for (i, segment) in segments.iter().enumerate() {
+            if !segment.is_empty() && !segment.contains('{') {
+                varying
+                    .entry(segment_shape(segments, i))
+                    .or_default()
+                    .insert(segment.clone());
+            }
+        }
+    }
+    varying
+}
+
+/// Splits a bracketed query key such as `filter[owner][id]` into its name and property path.
+/// Keys without a property, such as `ids[]`, are not objects.
+fn deep_object_key(key: &str) -> Option<(&str, Vec<&str>)> {
+    let (name, rest) = key.split_once('[')?;
+    let path = rest.strip_suffix(']')?.split("][").collect::<Vec<_>>();
+    let is_valid = !name.is_empty()
+        && !path[0].is_empty()
+        && path.iter().all(|p| !p.contains(['[', ']']))
+        && path[..path.len() - 1].iter().all(|p| !p.is_empty());
+    is_valid.then_some((name, path))
+}
+
+/// Adds a property to a `deepObject` parameter schema and its example, creating the objects
+/// along its path. Properties that clash with one already given are ignored.
+fn insert_deep_object_property(
+    schema: &mut openapi3::Schema,
+    example: &mut serde_json::Map<String, serde_json::Value>,
+    path: &[&str],
+    leaf: openapi3::Schema,
+) {
+    let Some((key, rest)) = path.split_first() else {
+        return;
+    };
+    let properties = schema.properties.get_or_insert_with(BTreeMap::new);
+    if rest.is_empty() {
+        if !properties.contains_key(*key) {
+            if let Some(value) = &leaf.example {
+                example.insert(key.to_string(), value.clone());
+            }
+            properties.insert(key.to_string(), leaf);
+        }
+        return;
+    }
+    let child = properties
+        .entry(key.to_string())
+        .or_insert_with(|| openapi3::Schema {
+            schema_type: Some("object".to_string()),
+            ..openapi3::Schema::default()
+        });
+    let child_example = example
+        .entry(key.to_string())
+        .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+    if let (Some("object"), serde_json::Value::Object(child_example)) =
+        (child.schema_type.as_deref(), child_example)
+    {
+        insert_deep_object_property(child, child_example, rest, leaf);
+    }
+}
+
+fn path_parameter_names(path: &str) -> Vec<String> {
+    URI_TEMPLATE_VARIABLE_RE
+        .captures_iter(path)
+        .map(|cap| cap[1].to_string())
+        .collect()
+}
+
+/// Recognizes the string formats most commonly found in API payloads.
+fn infer_string_format(value: &str) -> Option<String> {
+    let format = if UUID_RE.is_match(value) {
+        "uuid"
+    } else if DATE_TIME_RE.is_match(value) {
+        "date-time"
+    } else if DATE_RE.is_match(value) {
+        "date"
+    } else if EMAIL_RE.is_match(value) {
+        "email"
+    } else if URI_RE.is_match(value) {
+        "uri"
+    } else if value.parse::<std::net::Ipv4Addr>().is_ok() {
+        "ipv4"
+    } else if value.contains(':') && value.parse::<std::net::Ipv6Addr>().is_ok() {
+        "ipv6"
+    } else if BASE64_RE.is_match(value) && is_likely_base64(value) {
+        "byte"
+    } else {
+        return None;
+    };
+    Some(format.to_string())
+}
+
+/// Plain words, hex strings and API keys are valid base64 too, so only strings that carry
+/// padding, that mix cases and digits with `+` or `/`, or that are long enough and decode to
+/// text are treated as encoded bytes.
+fn is_likely_base64(value: &str) -> bool {
+    let is_mixed = value.chars().any(|c| c.is_ascii_uppercase())
+        && value.chars().any(|c| c.is_ascii_lowercase())
+        && value.chars().any(|c| c.is_ascii_digit());
+    value.ends_with('=')
+        || is_mixed && value.contains(['+', '/'])
+        || value.len() >= 24
+            && decode_base64(value)
+                .and_then(|bytes| String::from_utf8(bytes).ok())
+                .is_some_and(|text| !text.chars().any(|c| c.is_control() && !c.is_whitespace()))
+}
+
+/// Decodes standard base64, with or without padding.
+fn decode_base64(value: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(value.len() * 3 / 4);
+    let (mut bits, mut count) = (0u32, 0);
+    for c in value.trim_end_matches('=').bytes() {
+        let sextet = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        bits = (bits << 6) | u32::from(sextet);
+        count += 6;
+        if count >= 8 {
+            count -= 8;
+            bytes.push((bits >> count) as u8);
+            bits &= (1 << count) - 1;
+        }
+    }
+    Some(bytes)
+}
+
+/// The longest run of trailing words every name ends with, such as `Address` for
+/// `BillingAddress` and `ShippingAddress`.
+fn common_word_suffix(names: &[String]) -> Option<String> {
+    let first = names.first()?;
+    first
+        .char_indices()
+        .filter(|(_, c)| c.is_ascii_uppercase())
+        .map(|(i, _)| &first[i..])
+        .find(|suffix| names.iter().all(|name| name.ends_with(suffix)))
+        .map(String::from)
+}
+
+fn singularize(name: &str) -> String {
+    if let Some(stem) = name.strip_suffix("ies") {
+        format!("{stem}y")
+    } else if name.ends_with("ss") || name.ends_with("us") || name.len() <= 3 {
+        name.to_string()
+    } else if let Some(stem) = name.strip_suffix('s') {
+        stem.to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+fn extend_variable_map(
+    variable_map: &mut BTreeMap<String, serde_json::value::Value>,
+    variables: &[postman::Variable],
+) {
+    for v in variables {
+        if let (Some(v_name), Some(v_val)) = (&v.key, &v.value) {
+            if v_val != &serde_json::Value::String("".to_string()) {
+                variable_map.insert(v_name.clone(), v_val.clone());
+            }
+        }
+    }
+}
+
+fn extend_variable_map_from_environment(
+    variable_map: &mut BTreeMap<String, serde_json::value::Value>,
+    environment: &postman::Environment,
+) {
+    for v in environment
+        .values
+        .iter()
+        .filter(|v| v.enabled.unwrap_or(true))
+    {
+        if let (Some(v_name), Some(v_val)) = (&v.key, &v.value) {
+            if v_val != &serde_json::Value::String("".to_string()) {
+                variable_map.insert(v_name.clone(), v_val.clone());
+            }
+        }
+    }
+}
+
+fn extract_description(description: &Option<postman::DescriptionUnion>) -> Option<String> {
+    match description {
+        Some(d) => match d {
+            postman::DescriptionUnion::String(s) => Some(s.to_string()),
+            postman::DescriptionUnion::Description(desc) => {
+                desc.content.as_ref().map(|c| c.to_string())
+            }
+        },
+        None => None,
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openapi::v3_0::{MediaTypeExample, ObjectOrReference, Parameter, ParameterStyle, Schema};
+    use openapi::OpenApi;
+    use postman::Spec;
+
+    #[test]
+    fn test_extract_description() {
+        let description = Some(postman::DescriptionUnion::String("test".to_string()));
+        assert_eq!(extract_description(&description), Some("test".to_string()));
+
+        let description = Some(postman::DescriptionUnion::Description(
+            postman::Description {
+                content: Some("test".to_string()),
+                ..postman::Description::default()
+            },
+        ));
+        assert_eq!(extract_description(&description), Some("test".to_string()));
+
+        let description = None;
+        assert_eq!(extract_description(&description), None);
+    }
+
+    #[test]
+    fn test_generate_path_parameters() {
+        let empty_map = BTreeMap::<_, _>::new();
+        let transpiler = Transpiler::new(&empty_map);
+        let postman_variables = Some(vec![postman::Variable {
+            key: Some("test".to_string()),
+            value: Some(serde_json::Value::String("test_value".to_string())),
+            description: None,
+            ..postman::Variable::default()
+        }]);
+        let path_params = ["/test/".to_string(), "{{test_value}}".to_string()];
+        let params = transpiler.generate_path_parameters(&path_params, &postman_variables);
+        assert_eq!(params.unwrap().len(), 1);
+    }
+
+    #[test]
     fn test_generate_query_parameters() {
         let empty_map = BTreeMap::<_, _>::new();
         let transpiler = Transpiler::new(&empty_map);
@@ -1544,7 +4000,10 @@ mod tests {
             "/transform/collection",
             "/{method}/hello",
         ];
-        let OpenApi::V3_0(s) = oas;
+        let s = match oas {
+            OpenApi::V3_0(s) => s,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
         let keys = s.paths.keys().enumerate();
         for (i, k) in keys {
             assert_eq!(k, ordered_paths[i])
@@ -1552,237 +4011,1687 @@ mod tests {
     }
 
     #[test]
-    fn it_uses_the_correct_content_type_for_form_urlencoded_data() {
-        let spec: Spec = serde_json::from_str(get_fixture("echo.postman.json").as_ref()).unwrap();
-        let oas = Transpiler::transpile(spec);
+    fn it_uses_the_correct_content_type_for_form_urlencoded_data() {
+        let spec: Spec = serde_json::from_str(get_fixture("echo.postman.json").as_ref()).unwrap();
+        let oas = Transpiler::transpile(spec);
+        match oas {
+            OpenApi::V3_0(oas) => {
+                let b = oas
+                    .paths
+                    .get("/post")
+                    .unwrap()
+                    .post
+                    .as_ref()
+                    .unwrap()
+                    .request_body
+                    .as_ref()
+                    .unwrap();
+                if let ObjectOrReference::Object(b) = b {
+                    assert!(b.content.contains_key("application/x-www-form-urlencoded"));
+                }
+            }
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        }
+    }
+
+    #[test]
+    fn it_generates_headers_from_the_request() {
+        let spec: Spec = serde_json::from_str(get_fixture("echo.postman.json").as_ref()).unwrap();
+        let oas = Transpiler::transpile(spec);
+        match oas {
+            OpenApi::V3_0(oas) => {
+                let params = oas
+                    .paths
+                    .get("/headers")
+                    .unwrap()
+                    .get
+                    .as_ref()
+                    .unwrap()
+                    .parameters
+                    .as_ref()
+                    .unwrap();
+                let header = params
+                    .iter()
+                    .find(|p| {
+                        if let ObjectOrReference::Object(p) = p {
+                            p.location == "header"
+                        } else {
+                            false
+                        }
+                    })
+                    .unwrap();
+                let expected = ObjectOrReference::Object(Parameter {
+                    name: "my-sample-header".to_owned(),
+                    location: "header".to_owned(),
+                    description: Some("My Sample Header".to_owned()),
+                    required: Some(true),
+                    schema: Some(Schema {
+                        schema_type: Some("string".to_owned()),
+                        example: Some(serde_json::Value::String(
+                            "Lorem ipsum dolor sit amet".to_owned(),
+                        )),
+                        ..Schema::default()
+                    }),
+                    ..Parameter::default()
+                });
+                assert_eq!(header, &expected);
+            }
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        }
+    }
+
+    #[test]
+    fn it_generates_root_path_when_no_path_exists_in_collection() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("only-root-path.postman.json").as_ref()).unwrap();
+        let oas = Transpiler::transpile(spec);
+        match oas {
+            OpenApi::V3_0(oas) => {
+                assert!(oas.paths.contains_key("/"));
+            }
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        }
+    }
+
+    #[test]
+    fn it_parses_graphql_request_bodies() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("graphql.postman.json").as_ref()).unwrap();
+        let oas = Transpiler::transpile(spec);
+        match oas {
+            OpenApi::V3_0(oas) => {
+                let body = oas
+                    .paths
+                    .get("/")
+                    .unwrap()
+                    .post
+                    .as_ref()
+                    .unwrap()
+                    .request_body
+                    .as_ref()
+                    .unwrap();
+
+                if let ObjectOrReference::Object(body) = body {
+                    assert!(body.content.contains_key("application/json"));
+                    let content = body.content.get("application/json").unwrap();
+                    let schema = content.schema.as_ref().unwrap();
+                    if let ObjectOrReference::Object(schema) = schema {
+                        let props = schema.properties.as_ref().unwrap();
+                        assert!(props.contains_key("query"));
+                        assert!(props.contains_key("variables"));
+                    }
+                    let examples = content.examples.as_ref().unwrap();
+                    if let MediaTypeExample::Example { example } = examples {
+                        let example: serde_json::Map<String, serde_json::Value> =
+                            serde_json::from_value(example.clone()).unwrap();
+                        assert!(example.contains_key("query"));
+                        assert!(example.contains_key("variables"));
+                    }
+                }
+            }
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        }
+    }
+
+    #[test]
+    fn it_collapses_duplicate_query_params() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("duplicate-query-params.postman.json").as_ref())
+                .unwrap();
+        let oas = Transpiler::transpile(spec);
+        match oas {
+            OpenApi::V3_0(oas) => {
+                let query_param_names = oas
+                    .paths
+                    .get("/v2/json-rpc/{site id}")
+                    .unwrap()
+                    .post
+                    .as_ref()
+                    .unwrap()
+                    .parameters
+                    .as_ref()
+                    .unwrap()
+                    .iter()
+                    .filter_map(|p| match p {
+                        ObjectOrReference::Object(p) => {
+                            if p.location == "query" {
+                                Some(p.name.clone())
+                            } else {
+                                None
+                            }
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<String>>();
+
+                assert!(!query_param_names.is_empty());
+
+                let duplicates = (1..query_param_names.len())
+                    .filter_map(|i| {
+                        if query_param_names[i..].contains(&query_param_names[i - 1]) {
+                            Some(query_param_names[i - 1].clone())
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<std::collections::HashSet<String>>();
+
+                assert!(duplicates.is_empty(), "duplicates: {duplicates:?}");
+            }
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        }
+    }
+
+    #[test]
+    fn it_uses_the_security_requirement_on_operations() {
+        let spec: Spec = serde_json::from_str(get_fixture("echo.postman.json").as_ref()).unwrap();
+        let oas = Transpiler::transpile(spec);
+        match oas {
+            OpenApi::V3_0(oas) => {
+                let sr1 = oas
+                    .paths
+                    .get("/basic-auth")
+                    .unwrap()
+                    .get
+                    .as_ref()
+                    .unwrap()
+                    .security
+                    .as_ref()
+                    .unwrap();
+                assert_eq!(
+                    sr1.first()
+                        .unwrap()
+                        .requirement
+                        .as_ref()
+                        .unwrap()
+                        .get("basicAuth"),
+                    Some(&vec![])
+                );
+                let sr1 = oas
+                    .paths
+                    .get("/digest-auth")
+                    .unwrap()
+                    .get
+                    .as_ref()
+                    .unwrap()
+                    .security
+                    .as_ref()
+                    .unwrap();
+                assert_eq!(
+                    sr1.first()
+                        .unwrap()
+                        .requirement
+                        .as_ref()
+                        .unwrap()
+                        .get("digestAuth"),
+                    Some(&vec![])
+                );
+
+                let schemes = oas.components.unwrap().security_schemes.unwrap();
+                let basic = schemes.get("basicAuth").unwrap();
+                if let ObjectOrReference::Object(basic) = basic {
+                    match basic {
+                        openapi3::SecurityScheme::Http { scheme, .. } => {
+                            assert_eq!(scheme, "basic");
+                        }
+                        _ => panic!("Expected Http Security Scheme"),
+                    }
+                }
+                let digest = schemes.get("digestAuth").unwrap();
+                if let ObjectOrReference::Object(digest) = digest {
+                    match digest {
+                        openapi3::SecurityScheme::Http { scheme, .. } => {
+                            assert_eq!(scheme, "digest");
+                        }
+                        _ => panic!("Expected Http Security Scheme"),
+                    }
+                }
+            }
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        }
+    }
+
+    #[test]
+    fn it_resolves_variables_from_environments_and_globals() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("environment.postman.json").as_ref()).unwrap();
+        let options = TranspileOptions {
+            environments: vec![
+                get_fixture("environment.postman_environment.json")
+                    .parse()
+                    .unwrap(),
+                get_fixture("environment.postman_globals.json")
+                    .parse()
+                    .unwrap(),
+            ],
+            ..TranspileOptions::default()
+        };
+        let oas = Transpiler::transpile_with_options(spec, &options);
         match oas {
             OpenApi::V3_0(oas) => {
-                let b = oas
+                let servers = oas.servers.unwrap();
+                assert_eq!(servers.len(), 1);
+                assert_eq!(servers[0].url, "https://staging.example.com");
+                let paths = oas.paths.keys().collect::<Vec<_>>();
+                assert_eq!(
+                    paths,
+                    [
+                        "/v2/tenants/collection-tenant/users",
+                        "/v2/tenants/admin-tenant/admins"
+                    ]
+                );
+            }
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        }
+    }
+
+    #[test]
+    fn it_prefers_collection_variables_over_globals() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("environment.postman.json").as_ref()).unwrap();
+        let options = TranspileOptions {
+            environments: vec![get_fixture("environment.postman_globals.json")
+                .parse()
+                .unwrap()],
+            ..TranspileOptions::default()
+        };
+        let oas = Transpiler::transpile_with_options(spec, &options);
+        match oas {
+            OpenApi::V3_0(oas) => {
+                let servers = oas.servers.unwrap();
+                assert_eq!(servers[0].url, "https://collection.example.com");
+                assert!(oas
                     .paths
-                    .get("/post")
+                    .contains_key("/v2/tenants/collection-tenant/users"));
+            }
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        }
+    }
+
+    #[test]
+    fn it_generates_openapi_31_definitions() {
+        let spec: Spec = serde_json::from_str(get_fixture("users.postman.json").as_ref()).unwrap();
+        let options = TranspileOptions {
+            target: TargetSpec::OpenApi31,
+            ..TranspileOptions::default()
+        };
+        let oas = Transpiler::transpile_with_options(spec, &options);
+        match oas {
+            OpenApi::V3_1(oas) => {
+                assert_eq!(oas.openapi, "3.1.0");
+                let response = oas
+                    .paths
+                    .get("/users")
                     .unwrap()
-                    .post
+                    .get
                     .as_ref()
                     .unwrap()
-                    .request_body
+                    .responses
                     .as_ref()
+                    .unwrap()
+                    .get("200")
                     .unwrap();
-                if let ObjectOrReference::Object(b) = b {
-                    assert!(b.content.contains_key("application/x-www-form-urlencoded"));
+                let schema = response.content.as_ref().unwrap()["application/json"]
+                    .schema
+                    .as_ref()
+                    .unwrap();
+                let page = &schema.properties.as_ref().unwrap()["page"];
+                assert_eq!(page.examples, Some(vec![serde_json::json!(1)]));
+            }
+            _ => panic!("Expected an OpenAPI 3.1 definition"),
+        }
+    }
+
+    #[test]
+    fn it_extracts_shared_schemas_into_components() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("shared-schemas.postman.json").as_ref()).unwrap();
+        let options = TranspileOptions {
+            extract_shared_schemas: true,
+            ..TranspileOptions::default()
+        };
+        let oas = match Transpiler::transpile_with_options(spec, &options) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+
+        let schemas = oas.components.unwrap().schemas.unwrap();
+        assert_eq!(
+            schemas.keys().collect::<Vec<_>>(),
+            vec!["Address", "Profile", "Schema", "User"]
+        );
+        let user_ref = "#/components/schemas/User".to_string();
+        match &schemas["User"] {
+            ObjectOrReference::Object(user) => {
+                assert_eq!(user.properties.as_ref().unwrap()["name"].example, None)
+            }
+            _ => panic!("Expected a schema object"),
+        }
+
+        let response_schema = |path: &str| {
+            let op = oas.paths[path].get.as_ref().unwrap();
+            op.responses["200"].content.as_ref().unwrap()["application/json"]
+                .schema
+                .clone()
+        };
+        assert_eq!(
+            response_schema("/me"),
+            Some(ObjectOrReference::Ref {
+                ref_path: user_ref.clone()
+            })
+        );
+        assert_eq!(
+            response_schema("/accounts/7/profile"),
+            Some(ObjectOrReference::Ref {
+                ref_path: "#/components/schemas/Profile".to_string()
+            })
+        );
+        // Copies held by properties with nothing in common get a neutral name.
+        match response_schema("/route") {
+            Some(ObjectOrReference::Object(schema)) => {
+                let properties = schema.properties.unwrap();
+                let ref_path = |name: &str| properties[name].ref_path.clone().unwrap();
+                assert_eq!(ref_path("x1"), "#/components/schemas/Schema");
+                assert_eq!(ref_path("x2"), "#/components/schemas/Schema");
+                assert_eq!(ref_path("billingAddress"), "#/components/schemas/Address");
+                assert_eq!(ref_path("shippingAddress"), "#/components/schemas/Address");
+            }
+            _ => panic!("Expected an inline schema"),
+        }
+
+        let get_user = oas.paths["/users/{id}"].get.as_ref().unwrap();
+        let content = &get_user.responses["200"].content.as_ref().unwrap()["application/json"];
+        assert_eq!(
+            content.schema,
+            Some(ObjectOrReference::Ref {
+                ref_path: user_ref.clone()
+            })
+        );
+        let content = &get_user.responses["404"].content.as_ref().unwrap()["application/json"];
+        assert!(matches!(content.schema, Some(ObjectOrReference::Object(_))));
+
+        let list_users = oas.paths["/users"].get.as_ref().unwrap();
+        let content = &list_users.responses["200"].content.as_ref().unwrap()["application/json"];
+        match &content.schema {
+            Some(ObjectOrReference::Object(schema)) => {
+                let users = &schema.properties.as_ref().unwrap()["users"];
+                assert_eq!(users.items.as_ref().unwrap().ref_path, Some(user_ref));
+            }
+            _ => panic!("Expected an inline schema"),
+        }
+    }
+
+    #[test]
+    fn it_infers_string_formats() {
+        let cases = [
+            ("2022-09-01T10:15:30Z", Some("date-time")),
+            ("2022-09-01T10:15:30.123+02:00", Some("date-time")),
+            ("2022-09-01", Some("date")),
+            ("ada@example.com", Some("email")),
+            ("0b4a4c2e-8f4a-4e0f-9a3f-2f6d1a0b7c11", Some("uuid")),
+            ("https://example.com/users?page=2", Some("uri")),
+            ("192.168.0.1", Some("ipv4")),
+            ("2001:db8::ff00:42:8329", Some("ipv6")),
+            ("Q29udGVudCBvZiB0aGUgYmxvYg==", Some("byte")),
+            ("T3JkZXIgbnVtYmVyIDEyMzQ1Njc4", Some("byte")),
+            ("+vv8AQID/v/IZDIZDAYDAf/+", Some("byte")),
+            ("AbCdEfGh12345678", None),
+            ("k3Jd8sLq0PzX7vN2mB4cR9tY", None),
+            ("hello world", None),
+            ("abcdefghijklmnop", None),
+            ("12345", None),
+        ];
+        for (value, format) in cases {
+            let schema =
+                Transpiler::generate_schema(&serde_json::Value::String(value.to_string())).unwrap();
+            assert_eq!(schema.format.as_deref(), format, "format of {value}");
+        }
+    }
+
+    #[test]
+    fn it_drops_formats_that_disagree_when_merging() {
+        let date = Transpiler::generate_schema(&serde_json::json!("2022-09-01")).unwrap();
+        let date_time =
+            Transpiler::generate_schema(&serde_json::json!("2022-09-01T10:15:30Z")).unwrap();
+        let other_date = Transpiler::generate_schema(&serde_json::json!("2023-01-31")).unwrap();
+
+        let merged = Transpiler::merge_schemas(date.clone(), &other_date);
+        assert_eq!(merged.format.as_deref(), Some("date"));
+        let merged = Transpiler::merge_schemas(date, &date_time);
+        assert_eq!(merged.schema_type.as_deref(), Some("string"));
+        assert_eq!(merged.format, None);
+        assert_eq!(merged.any_of, None);
+    }
+
+    #[test]
+    fn it_distinguishes_integers_from_numbers() {
+        let small = Transpiler::generate_schema(&serde_json::json!(42)).unwrap();
+        assert_eq!(small.schema_type.as_deref(), Some("integer"));
+        assert_eq!(small.format.as_deref(), Some("int32"));
+
+        let large = Transpiler::generate_schema(&serde_json::json!(1_662_000_000_000_i64)).unwrap();
+        assert_eq!(large.schema_type.as_deref(), Some("integer"));
+        assert_eq!(large.format.as_deref(), Some("int64"));
+
+        let float = Transpiler::generate_schema(&serde_json::json!(4.2)).unwrap();
+        assert_eq!(float.schema_type.as_deref(), Some("number"));
+        assert_eq!(float.format, None);
+
+        let merged = Transpiler::merge_schemas(small.clone(), &large);
+        assert_eq!(merged.schema_type.as_deref(), Some("integer"));
+        assert_eq!(merged.format.as_deref(), Some("int64"));
+
+        for (original, new) in [(&small, &float), (&float, &small)] {
+            let merged = Transpiler::merge_schemas(original.clone(), new);
+            assert_eq!(merged.schema_type.as_deref(), Some("number"));
+            assert_eq!(merged.format, None);
+            assert_eq!(merged.any_of, None);
+        }
+    }
+
+    #[test]
+    fn it_requires_properties_present_in_every_example() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("required-properties.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let op = oas.paths["/users"].post.as_ref().unwrap();
+
+        let request_body = match &op.request_body {
+            Some(ObjectOrReference::Object(body)) => body,
+            _ => panic!("Expected an inline request body"),
+        };
+        match &request_body.content["application/json"].schema {
+            Some(ObjectOrReference::Object(schema)) => {
+                assert_eq!(schema.properties.as_ref().unwrap().len(), 3);
+                assert_eq!(schema.required, Some(vec!["name".to_string()]));
+            }
+            _ => panic!("Expected an inline schema"),
+        }
+
+        let content = &op.responses["201"].content.as_ref().unwrap()["application/json"];
+        match &content.schema {
+            Some(ObjectOrReference::Object(schema)) => {
+                assert_eq!(schema.properties.as_ref().unwrap().len(), 3);
+                assert_eq!(
+                    schema.required,
+                    Some(vec!["id".to_string(), "name".to_string()])
+                );
+            }
+            _ => panic!("Expected an inline schema"),
+        }
+    }
+
+    #[test]
+    fn it_requires_properties_present_in_every_array_item() {
+        let schema = Transpiler::generate_schema(&serde_json::json!([
+            {"id": 1, "tags": []},
+            {"id": 2}
+        ]))
+        .unwrap();
+        assert_eq!(schema.items.unwrap().required, Some(vec!["id".to_string()]));
+    }
+
+    #[test]
+    fn it_makes_types_nullable_when_merged_with_null() {
+        let string = Transpiler::generate_schema(&serde_json::json!("x")).unwrap();
+        let null = Transpiler::generate_schema(&serde_json::Value::Null).unwrap();
+
+        for (original, new) in [(&string, &null), (&null, &string)] {
+            let merged = Transpiler::merge_schemas(original.clone(), new);
+            assert_eq!(merged.schema_type.as_deref(), Some("string"));
+            assert_eq!(merged.nullable, Some(true));
+            assert_eq!(merged.any_of, None);
+        }
+    }
+
+    #[test]
+    fn it_describes_properties_that_are_always_null() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("nullable.postman.json").as_ref()).unwrap();
+        let (oas, diagnostics) =
+            Transpiler::transpile_with_diagnostics(spec, &TranspileOptions::default());
+        let oas = match oas {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let op = oas.paths["/things"].get.as_ref().unwrap();
+        let content = &op.responses["200"].content.as_ref().unwrap()["application/json"];
+        let properties = match &content.schema {
+            Some(ObjectOrReference::Object(schema)) => schema.properties.as_ref().unwrap(),
+            _ => panic!("Expected an inline schema"),
+        };
+        assert_eq!(properties["label"].schema_type.as_deref(), Some("string"));
+        assert_eq!(properties["label"].nullable, Some(true));
+        assert_eq!(properties["deletedAt"].schema_type, None);
+        assert_eq!(properties["deletedAt"].nullable, None);
+        assert!(properties["deletedAt"].description.is_some());
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|d| d.location.as_deref())
+                .collect::<Vec<_>>(),
+            vec![Some(
+                "#/paths/~1things/get/responses/200/content/application~1json/schema/properties/deletedAt"
+            )]
+        );
+    }
+
+    #[test]
+    fn it_describes_dictionary_values_that_are_always_null() {
+        let null = Transpiler::generate_schema(&serde_json::Value::Null).unwrap();
+        let mut schema = openapi3::Schema {
+            schema_type: Some("object".to_string()),
+            additional_properties: Some(ObjectOrReference::Object(Box::new(null))),
+            ..openapi3::Schema::default()
+        };
+        let mut diagnostics = vec![];
+        Transpiler::describe_null_only_schema(&mut schema, "#/schema", &mut diagnostics);
+
+        let values = match &schema.additional_properties {
+            Some(ObjectOrReference::Object(values)) => values,
+            _ => panic!("Expected an inline schema"),
+        };
+        assert_eq!(values.nullable, None);
+        assert!(values.description.is_some());
+        assert_eq!(
+            diagnostics[0].location.as_deref(),
+            Some("#/schema/additionalProperties")
+        );
+    }
+
+    #[test]
+    fn it_infers_enums_from_repeated_values() {
+        let spec: Spec = serde_json::from_str(get_fixture("enums.postman.json").as_ref()).unwrap();
+        let options = TranspileOptions {
+            enum_inference: Some(EnumInference::default()),
+            ..TranspileOptions::default()
+        };
+        let oas = match Transpiler::transpile_with_options(spec, &options) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let op = oas.paths["/orders"].get.as_ref().unwrap();
+        let content = &op.responses["200"].content.as_ref().unwrap()["application/json"];
+        let order = match &content.schema {
+            Some(ObjectOrReference::Object(schema)) => {
+                let orders = &schema.properties.as_ref().unwrap()["orders"];
+                orders.items.as_ref().unwrap().properties.clone().unwrap()
+            }
+            _ => panic!("Expected an inline schema"),
+        };
+        assert_eq!(
+            order["status"].enum_values,
+            Some(vec!["pending".to_string(), "shipped".to_string()])
+        );
+        assert_eq!(
+            order["currency"].enum_values,
+            Some(vec!["USD".to_string(), "EUR".to_string()])
+        );
+        assert_eq!(order["note"].enum_values, None);
+        assert_eq!(order["id"].enum_values, None);
+    }
+
+    #[test]
+    fn it_does_not_infer_enums_by_default() {
+        let spec: Spec = serde_json::from_str(get_fixture("enums.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let op = oas.paths["/orders"].get.as_ref().unwrap();
+        let content = &op.responses["200"].content.as_ref().unwrap()["application/json"];
+        assert!(!serde_json::to_string(&content.schema)
+            .unwrap()
+            .contains("\"enum\""));
+    }
+
+    #[test]
+    fn it_describes_dictionaries_with_additional_properties() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("dictionaries.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+
+        let op = oas.paths["/translations"].get.as_ref().unwrap();
+        let content = &op.responses["200"].content.as_ref().unwrap()["application/json"];
+        match &content.schema {
+            Some(ObjectOrReference::Object(schema)) => {
+                assert_eq!(schema.properties, None);
+                let values = match &schema.additional_properties {
+                    Some(ObjectOrReference::Object(values)) => values,
+                    _ => panic!("Expected an inline value schema"),
+                };
+                let properties = values.properties.as_ref().unwrap();
+                assert!(properties.contains_key("greeting"));
+                assert!(properties.contains_key("farewell"));
+                assert_eq!(values.required, Some(vec!["greeting".to_string()]));
+            }
+            _ => panic!("Expected an inline schema"),
+        }
+
+        let op = oas.paths["/scores"].get.as_ref().unwrap();
+        let content = &op.responses["200"].content.as_ref().unwrap()["application/json"];
+        match &content.schema {
+            Some(ObjectOrReference::Object(schema)) => {
+                let properties = schema.properties.as_ref().unwrap();
+                assert!(properties.contains_key("total"));
+                match &properties["scores"].additional_properties {
+                    Some(ObjectOrReference::Object(values)) => {
+                        assert_eq!(values.schema_type.as_deref(), Some("number"))
+                    }
+                    _ => panic!("Expected an inline value schema"),
                 }
             }
+            _ => panic!("Expected an inline schema"),
+        }
+    }
+
+    #[test]
+    fn it_keeps_properties_of_objects_that_are_not_dictionaries() {
+        let schema = Transpiler::generate_schema(&serde_json::json!({
+            "en": "Hello",
+            "name": "Greeting"
+        }))
+        .unwrap();
+        assert_eq!(schema.additional_properties, None);
+        assert_eq!(schema.properties.unwrap().len(), 2);
+
+        let schema = Transpiler::generate_schema(&serde_json::json!({
+            "1": "one",
+            "2": 2
+        }))
+        .unwrap();
+        assert_eq!(schema.additional_properties, None);
+
+        for value in [
+            serde_json::json!({ "id": "d1", "ip": "10.0.0.1", "os": "linux" }),
+            serde_json::json!({ "id": "d1", "os": "linux", "ko": "kernel" }),
+        ] {
+            let schema = Transpiler::generate_schema(&value).unwrap();
+            assert_eq!(schema.additional_properties, None);
+            assert_eq!(schema.properties.unwrap().len(), 3);
+        }
+    }
+
+    #[test]
+    fn it_parses_urls_given_as_strings() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("string-urls.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        assert_eq!(
+            oas.paths.keys().collect::<Vec<_>>(),
+            vec!["/users/{id}", "/posts", "/comments", "/health"]
+        );
+        let servers = oas.servers.unwrap();
+        assert_eq!(servers.len(), 1);
+        assert_eq!(servers[0].url, "https://api.example.com");
+
+        let path = &oas.paths["/users/{id}"];
+        assert_eq!(path.parameters.as_ref().unwrap().len(), 1);
+        let params = path.get.as_ref().unwrap().parameters.as_ref().unwrap();
+        match &params[0] {
+            ObjectOrReference::Object(p) => {
+                assert_eq!(p.name, "expand");
+                assert_eq!(p.location, "query");
+            }
+            _ => panic!("Expected an inline parameter"),
+        }
+    }
+
+    #[test]
+    fn it_converts_requests_given_as_strings() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("string-urls.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let op = oas.paths["/health"].get.as_ref().unwrap();
+        assert_eq!(op.operation_id.as_deref(), Some("healthCheck"));
+        match &op.parameters.as_ref().unwrap()[0] {
+            ObjectOrReference::Object(p) => assert_eq!(p.name, "verbose"),
+            _ => panic!("Expected an inline parameter"),
+        }
+        let content = &op.responses["200"].content.as_ref().unwrap()["application/json"];
+        assert!(content.schema.is_some());
+    }
+
+    #[test]
+    fn it_templates_servers_with_unresolved_variables() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("server-variables.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let servers = oas.servers.unwrap();
+        assert_eq!(
+            servers.iter().map(|s| s.url.as_str()).collect::<Vec<_>>(),
+            vec![
+                "https://{region}.api.example.com",
+                "{scheme}://api.example.com",
+                "/"
+            ]
+        );
+
+        let region = &servers[0].variables.as_ref().unwrap()["region"];
+        assert_eq!(region.default, "region");
+        assert_eq!(
+            region.description.as_deref(),
+            Some("The region hosting the account, e.g. `eu`.")
+        );
+        let scheme = &servers[1].variables.as_ref().unwrap()["scheme"];
+        assert_eq!(scheme.default, "https");
+        assert_eq!(
+            scheme.substitutions_enum,
+            Some(vec!["https".to_string(), "http".to_string()])
+        );
+        assert_eq!(servers[2].variables, None);
+        assert!(servers[2].description.is_some());
+    }
+
+    #[test]
+    fn it_keeps_ports_in_server_urls() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("base-paths.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let servers = oas.servers.unwrap();
+        assert_eq!(servers[0].url, "http://localhost:8080");
+        assert!(oas.paths.contains_key("/api/v1/users/{id}"));
+    }
+
+    #[test]
+    fn it_extracts_base_paths_into_servers() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("base-paths.postman.json").as_ref()).unwrap();
+        let options = TranspileOptions {
+            extract_base_paths: true,
+            ..TranspileOptions::default()
+        };
+        let oas = match Transpiler::transpile_with_options(spec, &options) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let servers = oas.servers.unwrap();
+        assert_eq!(
+            servers.iter().map(|s| s.url.as_str()).collect::<Vec<_>>(),
+            vec![
+                "http://localhost:8080/api/v1",
+                "http://uploads.example.com/v2"
+            ]
+        );
+        assert_eq!(
+            oas.paths.keys().collect::<Vec<_>>(),
+            vec!["/users", "/users/{id}", "/orders", "/files"]
+        );
+    }
+
+    #[test]
+    fn it_lists_servers_of_operations_called_on_some_hosts() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("multiple-hosts.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let urls = |servers: &Option<Vec<openapi3::Server>>| {
+            servers
+                .iter()
+                .flatten()
+                .map(|s| s.url.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(oas.servers.as_ref().unwrap().len(), 2);
+
+        let users = &oas.paths["/users"];
+        assert_eq!(urls(&users.servers), vec!["https://api.example.com"]);
+        assert_eq!(users.get.as_ref().unwrap().servers, None);
+
+        let files = &oas.paths["/files/{id}"];
+        assert_eq!(files.servers, None);
+        assert_eq!(
+            urls(&files.get.as_ref().unwrap().servers),
+            vec!["https://api.example.com"]
+        );
+        assert_eq!(
+            urls(&files.put.as_ref().unwrap().servers),
+            vec!["https://uploads.example.com"]
+        );
+
+        let status = &oas.paths["/status"];
+        assert_eq!(status.servers, None);
+        assert_eq!(status.get.as_ref().unwrap().servers, None);
+    }
+
+    #[test]
+    fn it_splits_definitions_by_server() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("multiple-hosts.postman.json").as_ref()).unwrap();
+        let definitions = Transpiler::transpile_by_server(spec, &TranspileOptions::default());
+        assert_eq!(
+            definitions
+                .iter()
+                .map(|(url, ..)| url.as_str())
+                .collect::<Vec<_>>(),
+            vec!["https://api.example.com", "https://uploads.example.com"]
+        );
+
+        let uploads = match &definitions[1].1 {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        assert_eq!(uploads.servers.as_ref().unwrap().len(), 1);
+        assert_eq!(
+            uploads.paths.keys().collect::<Vec<_>>(),
+            vec!["/files/{id}", "/status"]
+        );
+        let files = &uploads.paths["/files/{id}"];
+        assert!(files.get.is_none());
+        assert_eq!(files.put.as_ref().unwrap().servers, None);
+        let tags = uploads.tags.as_ref().unwrap();
+        assert_eq!(
+            tags.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(),
+            vec!["Files"]
+        );
+    }
+
+    #[test]
+    fn it_merges_paths_that_differ_only_in_parameter_names() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("duplicate-paths.postman.json").as_ref()).unwrap();
+        let (oas, diagnostics) =
+            Transpiler::transpile_with_diagnostics(spec, &TranspileOptions::default());
+        let oas = match oas {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        assert_eq!(
+            oas.paths.keys().collect::<Vec<_>>(),
+            vec!["/admin/{subresource}/{subresourceId}", "/users/{userId}"]
+        );
+        let admin = &oas.paths["/admin/{subresource}/{subresourceId}"];
+        assert!(admin.get.is_some() && admin.put.is_some() && admin.delete.is_some());
+        let names = admin
+            .parameters
+            .iter()
+            .flatten()
+            .map(|p| match p {
+                ObjectOrReference::Object(p) => p.name.as_str(),
+                _ => panic!("Expected a parameter object"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["subresource", "subresourceId"]);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].location.as_deref(),
+            Some("#/paths/~1admin~1{subresource}~1{subresourceId}")
+        );
+        assert!(diagnostics[0].message.contains("/admin/{sub}/{id}"));
+    }
+
+    #[test]
+    fn it_keeps_parameter_names_unique_when_merging_paths() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("reordered-path-parameters.postman.json").as_ref())
+                .unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        assert_eq!(
+            oas.paths.keys().collect::<Vec<_>>(),
+            vec!["/teams/{id}/{userId}"]
+        );
+        let params = oas.paths["/teams/{id}/{userId}"]
+            .parameters
+            .iter()
+            .flatten()
+            .map(|p| match p {
+                ObjectOrReference::Object(p) => p,
+                _ => panic!("Expected a parameter object"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            params.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+            vec!["id", "userId"]
+        );
+        assert_eq!(
+            params[0].schema.as_ref().unwrap().example,
+            Some(serde_json::json!(7))
+        );
+    }
+
+    #[test]
+    fn it_templatizes_literal_ids_in_paths() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("literal-ids.postman.json").as_ref()).unwrap();
+        let options = TranspileOptions {
+            templatize_path_ids: true,
+            ..TranspileOptions::default()
+        };
+        let oas = match Transpiler::transpile_with_options(spec, &options) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        assert_eq!(
+            oas.paths.keys().collect::<Vec<_>>(),
+            vec![
+                "/users/{userId}",
+                "/users/{userId}/orders/{orderId}",
+                "/invoices/{invoiceId}",
+                "/commits/{commitId}",
+                "/posts/{postId}",
+                "/v1/api-keys",
+                "/v1/access-tokens"
+            ]
+        );
+        let examples = oas.paths["/users/{userId}/orders/{orderId}"]
+            .parameters
+            .iter()
+            .flatten()
+            .map(|p| match p {
+                ObjectOrReference::Object(p) => p.schema.as_ref().unwrap().example.clone(),
+                _ => panic!("Expected a parameter object"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            examples,
+            vec![
+                Some(serde_json::json!(42)),
+                Some(serde_json::json!("9f1c2e4a-7b3d-4c5e-8f6a-1b2c3d4e5f60"))
+            ]
+        );
+    }
+
+    #[test]
+    fn it_keeps_literal_ids_in_paths_by_default() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("literal-ids.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        assert!(oas.paths.contains_key("/users/42"));
+        assert!(oas.paths.contains_key("/users/me"));
+    }
+
+    #[test]
+    fn it_keeps_head_requests_and_leaves_out_custom_methods() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("http-methods.postman.json").as_ref()).unwrap();
+        let (oas, diagnostics) =
+            Transpiler::transpile_with_diagnostics(spec, &TranspileOptions::default());
+        let oas = match oas {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let files = &oas.paths["/files/{id}"];
+        assert_eq!(
+            files.get.as_ref().unwrap().summary.as_deref(),
+            Some("Get File")
+        );
+        assert_eq!(
+            files.head.as_ref().unwrap().summary.as_deref(),
+            Some("Check File")
+        );
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|d| d.message.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "PURGE requests cannot be described, so \"Purge File\" was left out",
+                "LINK requests cannot be described, so \"Link File\" was left out",
+                "COPY requests cannot be described, so \"Copy File\" was left out"
+            ]
+        );
+    }
+
+    #[test]
+    fn it_merges_path_parameters_and_hoists_shared_parameters() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("path-parameters.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let users = &oas.paths["/users/{id}"];
+        let params = users
+            .parameters
+            .iter()
+            .flatten()
+            .map(|p| match p {
+                ObjectOrReference::Object(p) => p,
+                _ => panic!("Expected a parameter object"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            params
+                .iter()
+                .map(|p| (p.name.as_str(), p.location.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("id", "path"), ("x-tenant", "header")]
+        );
+        assert_eq!(
+            params[0].description.as_deref(),
+            Some("The ID of the user.")
+        );
+        let id = params[0].schema.as_ref().unwrap();
+        assert_eq!(id.schema_type.as_deref(), Some("integer"));
+        assert_eq!(id.example, Some(serde_json::json!(42)));
+
+        assert_eq!(users.get.as_ref().unwrap().parameters, None);
+        let put_params = users.put.as_ref().unwrap().parameters.as_ref().unwrap();
+        assert_eq!(put_params.len(), 1);
+        match &put_params[0] {
+            ObjectOrReference::Object(p) => assert_eq!(p.name, "dryRun"),
+            _ => panic!("Expected a parameter object"),
+        }
+    }
+
+    #[test]
+    fn it_types_path_parameters_from_variables() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("path-variable-types.postman.json").as_ref()).unwrap();
+        let options = TranspileOptions {
+            enum_inference: Some(EnumInference::default()),
+            ..TranspileOptions::default()
+        };
+        let oas = match Transpiler::transpile_with_options(spec, &options) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let schemas = |path: &str| {
+            oas.paths[path]
+                .parameters
+                .iter()
+                .flatten()
+                .map(|p| match p {
+                    ObjectOrReference::Object(p) => p.schema.clone().unwrap(),
+                    _ => panic!("Expected a parameter object"),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let report = schemas("/reports/{year}/{format}");
+        assert_eq!(report[0].schema_type.as_deref(), Some("integer"));
+        assert_eq!(report[0].example, Some(serde_json::json!(2024)));
+        assert_eq!(report[1].schema_type.as_deref(), Some("string"));
+        assert_eq!(
+            report[1].enum_values,
+            Some(vec!["pdf".to_string(), "csv".to_string()])
+        );
+
+        let flag = &schemas("/flags/{enabled}")[0];
+        assert_eq!(flag.schema_type.as_deref(), Some("boolean"));
+        assert_eq!(flag.example, Some(serde_json::json!(true)));
+
+        let item = &schemas("/items/{code}")[0];
+        assert_eq!(item.schema_type.as_deref(), Some("string"));
+        assert_eq!(item.example, Some(serde_json::json!("007")));
+        assert_eq!(item.enum_values, None);
+
+        let session = &schemas("/sessions/{sessionId}")[0];
+        assert_eq!(session.format.as_deref(), Some("uuid"));
+    }
+
+    #[test]
+    fn it_types_query_parameters_and_recognizes_arrays() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("query-parameters.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let params = oas.paths["/search"]
+            .get
+            .as_ref()
+            .unwrap()
+            .parameters
+            .iter()
+            .flatten()
+            .map(|p| match p {
+                ObjectOrReference::Object(p) => (p.name.as_str(), p),
+                _ => panic!("Expected a parameter object"),
+            })
+            .collect::<BTreeMap<_, _>>();
+        let schema_type = |name: &str| {
+            let schema = params[name].schema.as_ref().unwrap();
+            let items = schema.items.as_ref().and_then(|i| i.schema_type.clone());
+            (schema.schema_type.clone().unwrap(), items)
+        };
+
+        assert_eq!(
+            schema_type("tag"),
+            ("array".to_string(), Some("string".to_string()))
+        );
+        assert_eq!(
+            params["tag"].schema.as_ref().unwrap().example,
+            Some(serde_json::json!(["red", "blue"]))
+        );
+        assert_eq!(params["tag"].explode, Some(true));
+        assert_eq!(
+            schema_type("ids[]"),
+            ("array".to_string(), Some("integer".to_string()))
+        );
+        assert_eq!(
+            schema_type("fields"),
+            ("array".to_string(), Some("string".to_string()))
+        );
+        assert_eq!(params["fields"].style, Some(ParameterStyle::Form));
+        assert_eq!(params["fields"].explode, Some(false));
+        assert_eq!(schema_type("page"), ("integer".to_string(), None));
+        assert_eq!(schema_type("price"), ("number".to_string(), None));
+        assert_eq!(schema_type("active"), ("boolean".to_string(), None));
+        assert_eq!(
+            params["since"].schema.as_ref().unwrap().format.as_deref(),
+            Some("date")
+        );
+        assert_eq!(schema_type("q"), ("string".to_string(), None));
+        assert_eq!(params["q"].style, None);
+
+        // Commas only make a list when the key ends in `[]` or lists of different lengths are
+        // sent for it, as `fields` is above.
+        let params = oas.paths["/people"]
+            .get
+            .as_ref()
+            .unwrap()
+            .parameters
+            .iter()
+            .flatten()
+            .map(|p| match p {
+                ObjectOrReference::Object(p) => (p.name.as_str(), p),
+                _ => panic!("Expected a parameter object"),
+            })
+            .collect::<BTreeMap<_, _>>();
+        for name in ["name", "amount"] {
+            let schema = params[name].schema.as_ref().unwrap();
+            assert_eq!(schema.schema_type.as_deref(), Some("string"));
+            assert_eq!(params[name].style, None);
         }
+        assert_eq!(
+            params["name"].schema.as_ref().unwrap().example,
+            Some(serde_json::json!("Smith,John"))
+        );
+        let columns = params["columns[]"];
+        assert_eq!(
+            columns.schema.as_ref().unwrap().example,
+            Some(serde_json::json!(["name", "email"]))
+        );
+        assert_eq!(columns.explode, Some(false));
+    }
+
+    #[test]
+    fn it_groups_bracketed_query_keys_into_deep_objects() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("query-parameters.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let params = oas.paths["/issues"]
+            .get
+            .as_ref()
+            .unwrap()
+            .parameters
+            .iter()
+            .flatten()
+            .map(|p| match p {
+                ObjectOrReference::Object(p) => p,
+                _ => panic!("Expected a parameter object"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            params.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+            vec!["filter", "page", "sort"]
+        );
+
+        let filter = params[0];
+        assert_eq!(filter.style, Some(ParameterStyle::DeepObject));
+        assert_eq!(filter.explode, Some(true));
+        let schema = filter.schema.as_ref().unwrap();
+        assert_eq!(schema.schema_type.as_deref(), Some("object"));
+        assert_eq!(
+            schema.example,
+            Some(serde_json::json!({
+                "status": "open",
+                "owner": { "id": 5 },
+                "labels": ["bug", "ui"]
+            }))
+        );
+        let properties = schema.properties.as_ref().unwrap();
+        assert_eq!(properties["labels"].schema_type.as_deref(), Some("array"));
+        let owner = properties["owner"].properties.as_ref().unwrap();
+        assert_eq!(owner["id"].schema_type.as_deref(), Some("integer"));
+        assert_eq!(params[2].style, None);
     }
 
     #[test]
-    fn it_generates_headers_from_the_request() {
-        let spec: Spec = serde_json::from_str(get_fixture("echo.postman.json").as_ref()).unwrap();
-        let oas = Transpiler::transpile(spec);
-        match oas {
-            OpenApi::V3_0(oas) => {
-                let params = oas
-                    .paths
-                    .get("/headers")
-                    .unwrap()
-                    .get
-                    .as_ref()
-                    .unwrap()
-                    .parameters
-                    .as_ref()
-                    .unwrap();
-                let header = params
-                    .iter()
-                    .find(|p| {
-                        if let ObjectOrReference::Object(p) = p {
-                            p.location == "header"
-                        } else {
-                            false
-                        }
-                    })
-                    .unwrap();
-                let expected = ObjectOrReference::Object(Parameter {
-                    name: "my-sample-header".to_owned(),
-                    location: "header".to_owned(),
-                    description: Some("My Sample Header".to_owned()),
-                    schema: Some(Schema {
-                        schema_type: Some("string".to_owned()),
-                        example: Some(serde_json::Value::String(
-                            "Lorem ipsum dolor sit amet".to_owned(),
-                        )),
-                        ..Schema::default()
-                    }),
-                    ..Parameter::default()
-                });
-                assert_eq!(header, &expected);
-            }
-        }
+    fn it_keeps_bracketed_keys_flat_when_the_name_is_also_plain() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("query-parameters.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let params = |path: &str| {
+            oas.paths[path]
+                .get
+                .as_ref()
+                .unwrap()
+                .parameters
+                .iter()
+                .flatten()
+                .map(|p| match p {
+                    ObjectOrReference::Object(p) => p.clone(),
+                    _ => panic!("Expected a parameter object"),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let tickets = params("/tickets");
+        assert_eq!(
+            tickets.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+            vec!["filter", "filter[owner]"]
+        );
+        assert!(tickets.iter().all(|p| p.style.is_none()));
+        assert_eq!(
+            tickets[0].schema.as_ref().unwrap().schema_type.as_deref(),
+            Some("string")
+        );
+
+        // The plain `filter` of a later request leaves the object parameter as it was.
+        let filter = &params("/issues")[0];
+        assert_eq!(filter.style, Some(ParameterStyle::DeepObject));
+        let schema = filter.schema.as_ref().unwrap();
+        assert_eq!(schema.schema_type.as_deref(), Some("object"));
+        assert!(schema.properties.as_ref().unwrap().contains_key("status"));
     }
 
     #[test]
-    fn it_generates_root_path_when_no_path_exists_in_collection() {
+    fn it_describes_disabled_entries_as_optional() {
         let spec: Spec =
-            serde_json::from_str(get_fixture("only-root-path.postman.json").as_ref()).unwrap();
-        let oas = Transpiler::transpile(spec);
-        match oas {
-            OpenApi::V3_0(oas) => {
-                assert!(oas.paths.contains_key("/"));
+            serde_json::from_str(get_fixture("disabled-params.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let required = oas.paths["/orders"]
+            .get
+            .as_ref()
+            .unwrap()
+            .parameters
+            .iter()
+            .flatten()
+            .map(|p| match p {
+                ObjectOrReference::Object(p) => (p.name.as_str(), p.required),
+                _ => panic!("Expected a parameter object"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            required,
+            vec![
+                ("status", Some(true)),
+                ("page", None),
+                ("sort", Some(true)),
+                ("x-request-id", None),
+                ("x-debug", None)
+            ]
+        );
+        // A disabled `sort=desc` next to an enabled `sort=asc` is an alternative, not a repeat.
+        let sort = oas.paths["/orders"]
+            .get
+            .as_ref()
+            .unwrap()
+            .parameters
+            .iter()
+            .flatten()
+            .find_map(|p| match p {
+                ObjectOrReference::Object(p) if p.name == "sort" => Some(p),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(sort.style, None);
+        assert_eq!(sort.explode, None);
+        let schema = sort.schema.as_ref().unwrap();
+        assert_eq!(schema.schema_type.as_deref(), Some("string"));
+        assert_eq!(schema.example, Some(serde_json::json!("asc")));
+
+        let content = match &oas.paths["/orders"].post.as_ref().unwrap().request_body {
+            Some(ObjectOrReference::Object(body)) => {
+                &body.content["application/x-www-form-urlencoded"]
+            }
+            _ => panic!("Expected a request body"),
+        };
+        let schema = match &content.schema {
+            Some(ObjectOrReference::Object(schema)) => schema,
+            _ => panic!("Expected a schema object"),
+        };
+        assert!(schema.properties.as_ref().unwrap().contains_key("note"));
+        assert_eq!(schema.required, Some(vec!["product".to_string()]));
+        match &content.examples {
+            Some(MediaTypeExample::Examples { examples }) => match &examples["Create Order"] {
+                ObjectOrReference::Object(example) => assert_eq!(
+                    example.value,
+                    Some(serde_json::json!({ "product": "widget" }))
+                ),
+                _ => panic!("Expected an example object"),
+            },
+            _ => panic!("Expected examples"),
+        }
+
+        let content = match &oas.paths["/attachments"]
+            .post
+            .as_ref()
+            .unwrap()
+            .request_body
+        {
+            Some(ObjectOrReference::Object(body)) => &body.content["multipart/form-data"],
+            _ => panic!("Expected a request body"),
+        };
+        match &content.schema {
+            Some(ObjectOrReference::Object(schema)) => {
+                assert!(schema.properties.as_ref().unwrap().contains_key("caption"));
+                assert_eq!(schema.required, Some(vec!["file".to_string()]));
             }
+            _ => panic!("Expected a schema object"),
         }
     }
 
     #[test]
-    fn it_parses_graphql_request_bodies() {
+    fn it_leaves_out_disabled_entries_when_asked() {
         let spec: Spec =
-            serde_json::from_str(get_fixture("graphql.postman.json").as_ref()).unwrap();
-        let oas = Transpiler::transpile(spec);
-        match oas {
-            OpenApi::V3_0(oas) => {
-                let body = oas
-                    .paths
-                    .get("/")
-                    .unwrap()
-                    .post
-                    .as_ref()
-                    .unwrap()
-                    .request_body
-                    .as_ref()
-                    .unwrap();
+            serde_json::from_str(get_fixture("disabled-params.postman.json").as_ref()).unwrap();
+        let options = TranspileOptions {
+            exclude_disabled: true,
+            ..TranspileOptions::default()
+        };
+        let oas = match Transpiler::transpile_with_diagnostics(spec, &options).0 {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let names = oas.paths["/orders"]
+            .get
+            .as_ref()
+            .unwrap()
+            .parameters
+            .iter()
+            .flatten()
+            .map(|p| match p {
+                ObjectOrReference::Object(p) => p.name.as_str(),
+                _ => panic!("Expected a parameter object"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["status", "sort", "x-request-id"]);
 
-                if let ObjectOrReference::Object(body) = body {
-                    assert!(body.content.contains_key("application/json"));
-                    let content = body.content.get("application/json").unwrap();
-                    let schema = content.schema.as_ref().unwrap();
-                    if let ObjectOrReference::Object(schema) = schema {
-                        let props = schema.properties.as_ref().unwrap();
-                        assert!(props.contains_key("query"));
-                        assert!(props.contains_key("variables"));
-                    }
-                    let examples = content.examples.as_ref().unwrap();
-                    if let MediaTypeExample::Example { example } = examples {
-                        let example: serde_json::Map<String, serde_json::Value> =
-                            serde_json::from_value(example.clone()).unwrap();
-                        assert!(example.contains_key("query"));
-                        assert!(example.contains_key("variables"));
-                    }
+        let schema = match &oas.paths["/attachments"]
+            .post
+            .as_ref()
+            .unwrap()
+            .request_body
+        {
+            Some(ObjectOrReference::Object(body)) => {
+                match &body.content["multipart/form-data"].schema {
+                    Some(ObjectOrReference::Object(schema)) => schema.clone(),
+                    _ => panic!("Expected a schema object"),
                 }
             }
-        }
+            _ => panic!("Expected a request body"),
+        };
+        assert!(!schema.properties.unwrap().contains_key("caption"));
     }
 
     #[test]
-    fn it_collapses_duplicate_query_params() {
+    fn it_splits_cookie_headers_into_cookie_parameters() {
         let spec: Spec =
-            serde_json::from_str(get_fixture("duplicate-query-params.postman.json").as_ref())
-                .unwrap();
-        let oas = Transpiler::transpile(spec);
-        match oas {
-            OpenApi::V3_0(oas) => {
-                let query_param_names = oas
-                    .paths
-                    .get("/v2/json-rpc/{site id}")
-                    .unwrap()
-                    .post
-                    .as_ref()
-                    .unwrap()
-                    .parameters
-                    .as_ref()
-                    .unwrap()
-                    .iter()
-                    .filter_map(|p| match p {
-                        ObjectOrReference::Object(p) => {
-                            if p.location == "query" {
-                                Some(p.name.clone())
-                            } else {
-                                None
-                            }
-                        }
-                        _ => None,
-                    })
-                    .collect::<Vec<String>>();
+            serde_json::from_str(get_fixture("cookies.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let op = oas.paths["/profile"].get.as_ref().unwrap();
+        let params = op
+            .parameters
+            .iter()
+            .flatten()
+            .map(|p| match p {
+                ObjectOrReference::Object(p) => p,
+                _ => panic!("Expected a parameter object"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            params
+                .iter()
+                .map(|p| (p.name.as_str(), p.location.as_str(), p.required))
+                .collect::<Vec<_>>(),
+            vec![
+                ("sessionid", "cookie", Some(true)),
+                ("theme", "cookie", None),
+                ("visits", "cookie", Some(true))
+            ]
+        );
+        let session = params[0].schema.as_ref().unwrap();
+        assert_eq!(
+            session.example,
+            Some(serde_json::json!("b1946ac92492d2347c6235b4d2611184"))
+        );
+        let visits = params[2].schema.as_ref().unwrap();
+        assert_eq!(visits.schema_type.as_deref(), Some("integer"));
+        assert!(op.security.is_none());
+    }
 
-                assert!(!query_param_names.is_empty());
+    #[test]
+    fn it_describes_session_cookies_as_security_schemes() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("cookies.postman.json").as_ref()).unwrap();
+        let options = TranspileOptions {
+            cookie_auth: true,
+            ..TranspileOptions::default()
+        };
+        let oas = match Transpiler::transpile_with_diagnostics(spec, &options).0 {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let schemes = oas.components.unwrap().security_schemes.unwrap();
+        assert_eq!(
+            schemes["cookieAuth"],
+            ObjectOrReference::Object(openapi3::SecurityScheme::ApiKey {
+                name: "sessionid".to_string(),
+                location: "cookie".to_string(),
+            })
+        );
+
+        let op = oas.paths["/profile"].get.as_ref().unwrap();
+        assert_eq!(
+            op.security,
+            Some(vec![SecurityRequirement {
+                requirement: Some(BTreeMap::from([("cookieAuth".to_string(), vec![])])),
+            }])
+        );
+        let names = op
+            .parameters
+            .iter()
+            .flatten()
+            .map(|p| match p {
+                ObjectOrReference::Object(p) => p.name.as_str(),
+                _ => panic!("Expected a parameter object"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["theme", "visits"]);
+    }
 
-                let duplicates = (1..query_param_names.len())
-                    .filter_map(|i| {
-                        if query_param_names[i..].contains(&query_param_names[i - 1]) {
-                            Some(query_param_names[i - 1].clone())
-                        } else {
-                            None
-                        }
-                    })
-                    .collect::<std::collections::HashSet<String>>();
+    #[test]
+    fn it_requires_session_cookies_along_with_collection_auth() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("cookie-auth.postman.json").as_ref()).unwrap();
+        let options = TranspileOptions {
+            cookie_auth: true,
+            ..TranspileOptions::default()
+        };
+        let oas = match Transpiler::transpile_with_diagnostics(spec, &options).0 {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let op = oas.paths["/account"].get.as_ref().unwrap();
+        assert_eq!(
+            op.security,
+            Some(vec![SecurityRequirement {
+                requirement: Some(BTreeMap::from([
+                    ("bearerAuth".to_string(), vec![]),
+                    ("cookieAuth".to_string(), vec![]),
+                    ("cookieAuth2".to_string(), vec![]),
+                ])),
+            }])
+        );
+        let names = op
+            .parameters
+            .iter()
+            .flatten()
+            .map(|p| match p {
+                ObjectOrReference::Object(p) => p.name.as_str(),
+                _ => panic!("Expected a parameter object"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["csrftoken"]);
+
+        for name in [
+            "sessionid",
+            "JSESSIONID",
+            "connect.sid",
+            "remember_token",
+            "auth",
+        ] {
+            assert!(is_session_cookie(name), "{name}");
+        }
+        for name in [
+            "csrftoken",
+            "XSRF-TOKEN",
+            "csrf_token",
+            "tokens",
+            "authorship",
+            "visits",
+        ] {
+            assert!(!is_session_cookie(name), "{name}");
+        }
+    }
 
-                assert!(duplicates.is_empty(), "duplicates: {duplicates:?}");
+    #[test]
+    fn it_keeps_session_cookies_optional_when_a_request_does_not_send_them() {
+        let options = TranspileOptions {
+            cookie_auth: true,
+            ..TranspileOptions::default()
+        };
+        for reversed in [false, true] {
+            let mut spec: Spec =
+                serde_json::from_str(get_fixture("cookie-auth.postman.json").as_ref()).unwrap();
+            if reversed {
+                spec.item.reverse();
             }
+            let oas = match Transpiler::transpile_with_diagnostics(spec, &options).0 {
+                OpenApi::V3_0(oas) => oas,
+                _ => panic!("Expected an OpenAPI 3.0 definition"),
+            };
+            let op = oas.paths["/items"].get.as_ref().unwrap();
+            assert_eq!(
+                op.security,
+                Some(vec![
+                    SecurityRequirement {
+                        requirement: Some(BTreeMap::from([
+                            ("bearerAuth".to_string(), vec![]),
+                            ("cookieAuth".to_string(), vec![]),
+                        ])),
+                    },
+                    SecurityRequirement {
+                        requirement: Some(BTreeMap::from([("bearerAuth".to_string(), vec![])])),
+                    },
+                ]),
+                "reversed: {reversed}"
+            );
         }
     }
 
     #[test]
-    fn it_uses_the_security_requirement_on_operations() {
+    fn it_documents_cookies_set_by_saved_responses() {
+        let spec: Spec =
+            serde_json::from_str(get_fixture("response-cookies.postman.json").as_ref()).unwrap();
+        let oas = match Transpiler::transpile(spec) {
+            OpenApi::V3_0(oas) => oas,
+            _ => panic!("Expected an OpenAPI 3.0 definition"),
+        };
+        let response = &oas.paths["/session"].post.as_ref().unwrap().responses["200"];
+        let headers = response.headers.as_ref().unwrap();
+        assert!(!headers.contains_key("set-cookie"));
+        let header = match &headers["Set-Cookie"] {
+            ObjectOrReference::Object(header) => header,
+            _ => panic!("Expected a header object"),
+        };
+        assert_eq!(
+            header.description.as_deref(),
+            Some(
+                "- `sessionid`: domain `api.example.com`, path `/`, \
+                 expires Wed, 21 Oct 2026 07:28:00 GMT, HTTP only, secure\n\
+                 - `theme`: path `/`\n\
+                 - `lang`: domain `api.example.com`, path `/`, session cookie\n\
+                 - `tracking`: max age 3600 seconds\n\
+                 - `region`"
+            )
+        );
+        assert_eq!(
+            header.schema.as_ref().unwrap().example,
+            Some(serde_json::json!(
+                "sessionid=38afes7a8; Domain=api.example.com; Path=/; \
+                 Expires=Wed, 21 Oct 2026 07:28:00 GMT; HttpOnly; Secure"
+            ))
+        );
+    }
+
+    #[test]
+    fn it_generates_swagger_20_definitions() {
         let spec: Spec = serde_json::from_str(get_fixture("echo.postman.json").as_ref()).unwrap();
-        let oas = Transpiler::transpile(spec);
+        let options = TranspileOptions {
+            target: TargetSpec::Swagger20,
+            ..TranspileOptions::default()
+        };
+        let (oas, diagnostics) = Transpiler::transpile_with_diagnostics(spec, &options);
         match oas {
-            OpenApi::V3_0(oas) => {
-                let sr1 = oas
-                    .paths
-                    .get("/basic-auth")
-                    .unwrap()
-                    .get
-                    .as_ref()
-                    .unwrap()
-                    .security
-                    .as_ref()
-                    .unwrap();
-                assert_eq!(
-                    sr1.first()
-                        .unwrap()
-                        .requirement
-                        .as_ref()
-                        .unwrap()
-                        .get("basicAuth"),
-                    Some(&vec![])
-                );
-                let sr1 = oas
-                    .paths
-                    .get("/digest-auth")
-                    .unwrap()
-                    .get
-                    .as_ref()
-                    .unwrap()
-                    .security
-                    .as_ref()
-                    .unwrap();
-                assert_eq!(
-                    sr1.first()
-                        .unwrap()
-                        .requirement
-                        .as_ref()
-                        .unwrap()
-                        .get("digestAuth"),
-                    Some(&vec![])
-                );
-
-                let schemes = oas.components.unwrap().security_schemes.unwrap();
-                let basic = schemes.get("basicAuth").unwrap();
-                if let ObjectOrReference::Object(basic) = basic {
-                    match basic {
-                        openapi3::SecurityScheme::Http { scheme, .. } => {
-                            assert_eq!(scheme, "basic");
-                        }
-                        _ => panic!("Expected Http Security Scheme"),
-                    }
-                }
-                let digest = schemes.get("digestAuth").unwrap();
-                if let ObjectOrReference::Object(digest) = digest {
-                    match digest {
-                        openapi3::SecurityScheme::Http { scheme, .. } => {
-                            assert_eq!(scheme, "digest");
-                        }
-                        _ => panic!("Expected Http Security Scheme"),
-                    }
+            OpenApi::V2_0(oas) => {
+                assert_eq!(oas.swagger, "2.0");
+                assert_eq!(oas.host.as_deref(), Some("postman-echo.com"));
+                assert_eq!(oas.schemes, Some(vec!["https".to_string()]));
+
+                let op = oas.paths.get("/post").unwrap().post.as_ref().unwrap();
+                assert_eq!(op.consumes, Some(vec!["text/plain".to_string()]));
+                match &op.parameters.as_ref().unwrap()[0] {
+                    ObjectOrReference::Object(p) => assert_eq!(p.location, "body"),
+                    ObjectOrReference::Ref { .. } => panic!("Expected a body parameter"),
                 }
+
+                let definitions = oas.security_definitions.unwrap();
+                assert!(definitions.contains_key("basicAuth"));
+                assert!(!definitions.contains_key("digestAuth"));
+                let op = oas.paths.get("/digest-auth").unwrap().get.as_ref().unwrap();
+                assert_eq!(op.security, None);
             }
+            _ => panic!("Expected a Swagger 2.0 definition"),
         }
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.location.as_deref() == Some("#/components/securitySchemes/digestAuth")));
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.location.as_deref() == Some("#/paths/~1post/post/requestBody")));
     }
 
     fn get_fixture(filename: &str) -> String {
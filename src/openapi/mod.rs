@@ -17,18 +17,40 @@ use std::result::Result as StdResult;
 use std::{fs::File, io::Read, path::Path};
 
 pub mod error;
+pub mod v2_0;
 pub mod v3_0;
+pub mod v3_1;
 
 pub use error::Error;
 
 const MINIMUM_OPENAPI30_VERSION: &str = ">= 3.0";
+const MINIMUM_OPENAPI31_VERSION: &str = ">= 3.1";
 
 pub type Result<T> = StdResult<T, Error>;
 
+/// Escapes a token of a JSON pointer, such as a path used as a key of `paths`.
+pub(crate) fn escape_json_pointer(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Whether a media type, ignoring any parameters such as `boundary`, carries form fields.
+pub(crate) fn is_form_media_type(media_type: &str) -> bool {
+    let essence = media_type.split(';').next().unwrap_or_default().trim();
+    essence.eq_ignore_ascii_case("application/x-www-form-urlencoded")
+        || essence.eq_ignore_ascii_case("multipart/form-data")
+}
+
 /// Supported versions of the OpenApi.
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(untagged)]
 pub enum OpenApi {
+    /// Version 2.0 of the OpenApi specification, also known as Swagger 2.0.
+    ///
+    /// Refer to the official
+    /// [specification](https://github.com/OAI/OpenAPI-Specification/blob/main/versions/2.0.md)
+    /// for more information.
+    #[allow(non_camel_case_types)]
+    V2_0(Box<v2_0::Spec>),
     /// Version 3.0.1 of the OpenApi specification.
     ///
     /// Refer to the official
@@ -36,6 +58,13 @@ pub enum OpenApi {
     /// for more information.
     #[allow(non_camel_case_types)]
     V3_0(Box<v3_0::Spec>),
+    /// Version 3.1.0 of the OpenApi specification.
+    ///
+    /// Refer to the official
+    /// [specification](https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md)
+    /// for more information.
+    #[allow(non_camel_case_types)]
+    V3_1(Box<v3_1::Spec>),
 }
 
 /// deserialize an open api spec from a path
@@ -53,7 +82,21 @@ pub fn from_reader<R>(read: R) -> Result<OpenApi>
 where
     R: Read,
 {
-    Ok(serde_yaml::from_reader::<R, OpenApi>(read)?)
+    // Most 3.1 documents are also structurally valid 3.0 documents, so the version field
+    // decides which model is used rather than the order of the untagged variants.
+    let value = serde_yaml::from_reader::<R, serde_yaml::Value>(read)?;
+    if value.get("swagger").is_some() {
+        return Ok(OpenApi::V2_0(Box::new(serde_yaml::from_value(value)?)));
+    }
+    let is_v3_1 = value
+        .get("openapi")
+        .and_then(|v| v.as_str())
+        .is_some_and(|v| v.starts_with("3.1"));
+    if is_v3_1 {
+        Ok(OpenApi::V3_1(Box::new(serde_yaml::from_value(value)?)))
+    } else {
+        Ok(OpenApi::V3_0(Box::new(serde_yaml::from_value(value)?)))
+    }
 }
 
 /// serialize to a yaml string
@@ -99,6 +142,7 @@ mod tests {
     /// paths, comparing the result.
     /// 1. File -> `String` -> `serde_yaml::Value` -> `serde_json::Value` -> `String`
     /// 2. File -> `Spec` -> `serde_json::Value` -> `String`
+    ///
     /// Both conversion of `serde_json::Value` -> `String` are done
     /// using `serde_json::to_string_pretty`.
     /// Since the first conversion is independant of the current crate (and only
@@ -114,7 +158,7 @@ mod tests {
         //     File -> `String` -> `serde_yaml::Value` -> `serde_json::Value` -> `String`
 
         // Read the original file to string
-        let spec_yaml_str = read_to_string(&input_file)
+        let spec_yaml_str = read_to_string(input_file)
             .unwrap_or_else(|e| panic!("failed to read contents of {:?}: {}", input_file, e));
         // Convert YAML string to JSON string
         let spec_json_str = convert_yaml_str_to_json(&spec_yaml_str);
@@ -123,7 +167,7 @@ mod tests {
         //     File -> `Spec` -> `serde_json::Value` -> `String`
 
         // Parse the input file
-        let parsed_spec = from_path(&input_file).unwrap();
+        let parsed_spec = from_path(input_file).unwrap();
         // Convert to serde_json::Value
         let parsed_spec_json = serde_json::to_value(parsed_spec).unwrap();
         // Convert to a JSON string
@@ -185,10 +229,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn can_deserialize_v2() {
+        for entry in fs::read_dir("src/openapi/data/v2").unwrap() {
+            let path = entry.unwrap().path();
+            println!("Testing if {:?} is deserializable", path);
+            match from_path(path).unwrap() {
+                OpenApi::V2_0(spec) => assert_eq!(spec.swagger, "2.0"),
+                _ => panic!("expected a Swagger 2.0 definition"),
+            }
+        }
+    }
+
+    #[test]
+    fn can_deserialize_v3_1() {
+        for entry in fs::read_dir("src/openapi/data/v3.1").unwrap() {
+            let path = entry.unwrap().path();
+            println!("Testing if {:?} is deserializable", path);
+            match from_path(path).unwrap() {
+                OpenApi::V3_1(spec) => {
+                    spec.validate_version().unwrap();
+                }
+                _ => panic!("expected an OpenAPI 3.1 definition"),
+            }
+        }
+    }
+
+    #[test]
+    fn can_deserialize_type_arrays_v3_1() {
+        let openapi = from_path("src/openapi/data/v3.1/webhook-example.yaml").unwrap();
+        let spec = match openapi {
+            OpenApi::V3_1(spec) => spec,
+            _ => panic!("expected an OpenAPI 3.1 definition"),
+        };
+        assert!(spec.webhooks.unwrap().contains_key("newPet"));
+        let schemas = spec.components.unwrap().schemas.unwrap();
+        let tag = schemas.get("Pet").unwrap().properties.as_ref().unwrap()["tag"].clone();
+        assert_eq!(
+            tag.schema_type,
+            Some(v3_1::SchemaType::Multiple(vec![
+                "string".to_string(),
+                "null".to_string()
+            ]))
+        );
+    }
+
     #[test]
     fn can_deserialize_one_of_v3() {
         let openapi = from_path("src/openapi/data/v3.0/petstore-expanded.yaml").unwrap();
-        let OpenApi::V3_0(spec) = openapi;
+        let spec = match openapi {
+            OpenApi::V3_0(spec) => spec,
+            _ => panic!("expected an OpenAPI 3.0 definition"),
+        };
         let components = spec.components.unwrap();
         let schemas = components.schemas.unwrap();
         let obj_or_ref = schemas.get("PetSpecies");
@@ -200,4 +292,14 @@ mod tests {
             panic!("object should have been schema");
         }
     }
+
+    #[test]
+    fn recognizes_form_media_types_with_parameters() {
+        assert!(is_form_media_type("application/x-www-form-urlencoded"));
+        assert!(is_form_media_type(
+            "multipart/form-data; boundary=X-BOUNDARY"
+        ));
+        assert!(!is_form_media_type("multipart/form-data-extended"));
+        assert!(!is_form_media_type("application/json"));
+    }
 }
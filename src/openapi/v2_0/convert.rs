@@ -0,0 +1,1147 @@
+//! Conversion of OpenAPI 3.0 definitions into Swagger 2.0 definitions.
+//!
+//! Swagger 2.0 cannot express everything OpenAPI 3.0 can, so the conversion is lossy. Every
+//! feature that is dropped or approximated is reported as a [`Warning`].
+
+use super::{
+    Info, Items, ObjectOrReference, Operation, Parameter, PathItem, Response, Schema,
+    SecurityRequirement, SecurityScheme, Spec,
+};
+use crate::openapi::{escape_json_pointer, is_form_media_type, v3_0};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A part of an OpenAPI 3.0 definition that could not be represented exactly in Swagger 2.0.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Warning {
+    /// A JSON pointer to the affected object in the OpenAPI 3.0 definition.
+    pub location: String,
+    pub message: String,
+}
+
+impl Spec {
+    /// Converts an OpenAPI 3.0 definition, returning a warning for every lossy conversion.
+    pub fn from_v3_0(spec: v3_0::Spec) -> (Spec, Vec<Warning>) {
+        let mut converter = Converter::default();
+        let spec = converter.convert(spec);
+        (spec, converter.warnings)
+    }
+}
+
+#[derive(Default)]
+struct Converter {
+    request_bodies: BTreeMap<String, ObjectOrReference<v3_0::RequestBody>>,
+    dropped_security_schemes: BTreeSet<String>,
+    warnings: Vec<Warning>,
+}
+
+impl Converter {
+    fn warn(&mut self, location: &str, message: String) {
+        self.warnings.push(Warning {
+            location: location.to_string(),
+            message,
+        });
+    }
+
+    fn convert(&mut self, spec: v3_0::Spec) -> Spec {
+        let (host, base_path, schemes) = self.convert_servers(spec.servers.unwrap_or_default());
+
+        let mut definitions = None;
+        let mut parameters = None;
+        let mut responses = None;
+        let mut security_definitions = None;
+        if let Some(components) = spec.components {
+            definitions = components.schemas.map(|schemas| {
+                schemas
+                    .into_iter()
+                    .map(|(name, schema)| {
+                        let location =
+                            format!("#/components/schemas/{}", escape_json_pointer(&name));
+                        (name, self.convert_schema_ref(schema, &location))
+                    })
+                    .collect::<BTreeMap<_, _>>()
+            });
+            parameters = components.parameters.map(|params| {
+                params
+                    .into_iter()
+                    .filter_map(|(name, param)| {
+                        let location = format!("#/components/parameters/{}", escape_json_pointer(&name));
+                        match param {
+                            ObjectOrReference::Object(param) => self
+                                .convert_parameter(param, &location)
+                                .map(|param| (name, param)),
+                            ObjectOrReference::Ref { .. } => {
+                                self.warn(&location, "parameter references cannot be reused in Swagger 2.0 and were removed".to_string());
+                                None
+                            }
+                        }
+                    })
+                    .collect::<BTreeMap<_, _>>()
+            });
+            responses = components.responses.map(|defs| {
+                defs.into_iter()
+                    .filter_map(|(name, response)| {
+                        let location = format!("#/components/responses/{}", escape_json_pointer(&name));
+                        match response {
+                            ObjectOrReference::Object(response) => {
+                                Some((name, self.convert_response(response, &location).0))
+                            }
+                            ObjectOrReference::Ref { .. } => {
+                                self.warn(&location, "response references cannot be reused in Swagger 2.0 and were removed".to_string());
+                                None
+                            }
+                        }
+                    })
+                    .collect::<BTreeMap<_, _>>()
+            });
+            security_definitions = components.security_schemes.map(|schemes| {
+                schemes
+                    .into_iter()
+                    .filter_map(|(name, scheme)| {
+                        let converted = self.convert_security_scheme(&name, scheme);
+                        if converted.is_none() {
+                            self.dropped_security_schemes.insert(name.clone());
+                        }
+                        converted.map(|scheme| (name, scheme))
+                    })
+                    .collect::<BTreeMap<_, _>>()
+            });
+            if let Some(request_bodies) = components.request_bodies {
+                self.request_bodies = request_bodies;
+            }
+            if components.examples.is_some_and(|m| !m.is_empty()) {
+                self.warn(
+                    "#/components/examples",
+                    "reusable examples are not supported by Swagger 2.0 and were removed"
+                        .to_string(),
+                );
+            }
+            if components.headers.is_some_and(|m| !m.is_empty()) {
+                self.warn(
+                    "#/components/headers",
+                    "reusable headers are not supported by Swagger 2.0 and were removed"
+                        .to_string(),
+                );
+            }
+            if components.links.is_some_and(|m| !m.is_empty()) {
+                self.warn(
+                    "#/components/links",
+                    "links are not supported by Swagger 2.0 and were removed".to_string(),
+                );
+            }
+            if components.callbacks.is_some_and(|m| !m.is_empty()) {
+                self.warn(
+                    "#/components/callbacks",
+                    "callbacks are not supported by Swagger 2.0 and were removed".to_string(),
+                );
+            }
+        }
+
+        let paths = spec
+            .paths
+            .into_iter()
+            .map(|(path, item)| {
+                let item = self
+                    .convert_path_item(item, &format!("#/paths/{}", escape_json_pointer(&path)));
+                (path, item)
+            })
+            .collect();
+
+        Spec {
+            swagger: String::from("2.0"),
+            info: Info {
+                title: spec.info.title,
+                description: spec.info.description,
+                terms_of_service: spec.info.terms_of_service,
+                contact: spec.info.contact,
+                license: spec.info.license,
+                version: spec.info.version,
+            },
+            host,
+            base_path,
+            schemes,
+            consumes: None,
+            produces: None,
+            paths,
+            definitions,
+            parameters,
+            responses,
+            security_definitions,
+            security: self.convert_security(spec.security),
+            tags: spec.tags,
+            external_docs: spec.external_docs,
+        }
+    }
+
+    /// Swagger 2.0 describes a single host and base path, so only servers that share the
+    /// first server's host and base path are kept; they contribute their schemes.
+    fn convert_servers(
+        &mut self,
+        servers: Vec<v3_0::Server>,
+    ) -> (Option<String>, Option<String>, Option<Vec<String>>) {
+        let mut host = None;
+        let mut base_path = None;
+        let mut schemes = Vec::<String>::new();
+        for (i, server) in servers.into_iter().enumerate() {
+            let location = format!("#/servers/{i}");
+            let mut url = server.url.clone();
+            if let Some(variables) = server.variables {
+                for (name, variable) in variables {
+                    url = url.replace(&format!("{{{name}}}"), &variable.default);
+                }
+                self.warn(
+                    &location,
+                    "server variables are not supported by Swagger 2.0; their default values were used".to_string(),
+                );
+            }
+            let (scheme, server_host, server_base_path) = split_url(&url);
+            if i == 0 {
+                host = server_host;
+                base_path = server_base_path;
+            } else if server_host != host || server_base_path != base_path {
+                self.warn(
+                    &location,
+                    format!(
+                        "server `{}` was removed; Swagger 2.0 supports a single host and base path",
+                        server.url
+                    ),
+                );
+                continue;
+            }
+            if let Some(scheme) = scheme {
+                if !schemes.contains(&scheme) {
+                    schemes.push(scheme);
+                }
+            }
+        }
+        let schemes = if schemes.is_empty() {
+            None
+        } else {
+            Some(schemes)
+        };
+        (host, base_path, schemes)
+    }
+
+    fn convert_path_item(&mut self, item: v3_0::PathItem, location: &str) -> PathItem {
+        if item.summary.is_some() || item.description.is_some() {
+            self.warn(
+                location,
+                "path item summaries and descriptions are not supported by Swagger 2.0 and were removed".to_string(),
+            );
+        }
+        if item.servers.is_some_and(|s| !s.is_empty()) {
+            self.warn(
+                location,
+                "path item servers are not supported by Swagger 2.0 and were removed".to_string(),
+            );
+        }
+        if item.trace.is_some() {
+            self.warn(
+                &format!("{location}/trace"),
+                "TRACE operations are not supported by Swagger 2.0 and were removed".to_string(),
+            );
+        }
+        let mut convert_operation = |op: Option<v3_0::Operation>, method: &str| {
+            op.map(|op| self.convert_operation(op, &format!("{location}/{method}")))
+        };
+        let get = convert_operation(item.get, "get");
+        let put = convert_operation(item.put, "put");
+        let post = convert_operation(item.post, "post");
+        let delete = convert_operation(item.delete, "delete");
+        let options = convert_operation(item.options, "options");
+        let head = convert_operation(item.head, "head");
+        let patch = convert_operation(item.patch, "patch");
+        PathItem {
+            reference: item.reference,
+            get,
+            put,
+            post,
+            delete,
+            options,
+            head,
+            patch,
+            parameters: item
+                .parameters
+                .map(|params| self.convert_parameters(params, &format!("{location}/parameters"))),
+        }
+    }
+
+    fn convert_operation(&mut self, op: v3_0::Operation, location: &str) -> Operation {
+        let mut parameters = op
+            .parameters
+            .map(|params| self.convert_parameters(params, &format!("{location}/parameters")))
+            .unwrap_or_default();
+
+        let mut consumes = Vec::<String>::new();
+        if let Some(body) = op.request_body {
+            let body_location = format!("{location}/requestBody");
+            let body = match body {
+                ObjectOrReference::Object(body) => Some(body),
+                ObjectOrReference::Ref { ref_path } => self.resolve_request_body(&ref_path),
+            };
+            match body {
+                Some(body) => {
+                    let (body_consumes, body_params) =
+                        self.convert_request_body(body, &body_location);
+                    consumes = body_consumes;
+                    parameters.extend(body_params.into_iter().map(ObjectOrReference::Object));
+                }
+                None => self.warn(
+                    &body_location,
+                    "the request body reference could not be resolved and was removed".to_string(),
+                ),
+            }
+        }
+
+        let mut produces = Vec::<String>::new();
+        let responses = op
+            .responses
+            .into_iter()
+            .map(|(code, response)| {
+                let response_location =
+                    format!("{location}/responses/{}", escape_json_pointer(&code));
+                let (response, response_produces) =
+                    self.convert_response(response, &response_location);
+                for media_type in response_produces {
+                    if !produces.contains(&media_type) {
+                        produces.push(media_type);
+                    }
+                }
+                (code, response)
+            })
+            .collect();
+
+        if op.callbacks.is_some_and(|c| !c.is_empty()) {
+            self.warn(
+                location,
+                "callbacks are not supported by Swagger 2.0 and were removed".to_string(),
+            );
+        }
+        if op.servers.is_some_and(|s| !s.is_empty()) {
+            self.warn(
+                location,
+                "operation servers are not supported by Swagger 2.0 and were removed".to_string(),
+            );
+        }
+
+        Operation {
+            tags: op.tags,
+            summary: op.summary,
+            description: op.description,
+            external_docs: op.external_docs,
+            operation_id: op.operation_id,
+            consumes: if consumes.is_empty() {
+                None
+            } else {
+                Some(consumes)
+            },
+            produces: if produces.is_empty() {
+                None
+            } else {
+                Some(produces)
+            },
+            parameters: if parameters.is_empty() {
+                None
+            } else {
+                Some(parameters)
+            },
+            responses,
+            deprecated: op.deprecated,
+            security: self.convert_security(op.security),
+        }
+    }
+
+    fn resolve_request_body(&self, ref_path: &str) -> Option<v3_0::RequestBody> {
+        let name = ref_path.strip_prefix("#/components/requestBodies/")?;
+        match self.request_bodies.get(name)? {
+            ObjectOrReference::Object(body) => Some(body.clone()),
+            ObjectOrReference::Ref { ref_path } => self.resolve_request_body(ref_path),
+        }
+    }
+
+    fn convert_parameters(
+        &mut self,
+        params: Vec<ObjectOrReference<v3_0::Parameter>>,
+        location: &str,
+    ) -> Vec<ObjectOrReference<Parameter>> {
+        params
+            .into_iter()
+            .filter_map(|param| match param {
+                ObjectOrReference::Object(param) => {
+                    let param_location = format!("{location}/{}", escape_json_pointer(&param.name));
+                    self.convert_parameter(param, &param_location)
+                        .map(ObjectOrReference::Object)
+                }
+                ObjectOrReference::Ref { ref_path } => Some(ObjectOrReference::Ref {
+                    ref_path: convert_ref_path(ref_path),
+                }),
+            })
+            .collect()
+    }
+
+    fn convert_parameter(&mut self, param: v3_0::Parameter, location: &str) -> Option<Parameter> {
+        if param.location == "cookie" {
+            self.warn(
+                location,
+                format!(
+                    "cookie parameter `{}` is not supported by Swagger 2.0 and was removed",
+                    param.name
+                ),
+            );
+            return None;
+        }
+        let schema = param.schema.unwrap_or_else(|| v3_0::Schema {
+            schema_type: param.param_type.clone(),
+            format: param.format.clone(),
+            ..v3_0::Schema::default()
+        });
+        let items = self.convert_items(&schema, location);
+        let collection_format = if items.items_type.as_deref() == Some("array") {
+            match (param.location.as_str(), &param.style) {
+                ("query", Some(v3_0::ParameterStyle::Form) | None)
+                    if param.explode != Some(false) =>
+                {
+                    Some("multi".to_string())
+                }
+                _ => Some("csv".to_string()),
+            }
+        } else {
+            None
+        };
+        Some(Parameter {
+            name: param.name,
+            location: param.location,
+            description: param.description,
+            required: param.required,
+            schema: None,
+            param_type: items.items_type,
+            format: items.format,
+            items: items.items.map(|items| *items),
+            collection_format,
+            default: items.default,
+            enum_values: items.enum_values,
+        })
+    }
+
+    /// Describes a parameter or header schema with the primitive types Swagger 2.0 allows
+    /// outside of body parameters.
+    fn convert_items(&mut self, schema: &v3_0::Schema, location: &str) -> Items {
+        let is_primitive = schema.ref_path.is_none()
+            && schema.properties.is_none()
+            && schema.schema_type.as_deref() != Some("object");
+        if !is_primitive {
+            self.warn(
+                location,
+                "only primitive and array values are supported outside of request bodies in Swagger 2.0; the value is described as a string".to_string(),
+            );
+            return Items {
+                items_type: Some("string".to_string()),
+                ..Items::default()
+            };
+        }
+        let items = if schema.schema_type.as_deref() == Some("array") {
+            let item_schema = schema.items.as_deref().cloned().unwrap_or_default();
+            Some(Box::new(self.convert_items(&item_schema, location)))
+        } else {
+            None
+        };
+        Items {
+            items_type: Some(
+                schema
+                    .schema_type
+                    .clone()
+                    .unwrap_or_else(|| "string".to_string()),
+            ),
+            format: schema.format.clone(),
+            items,
+            collection_format: None,
+            default: schema.default.clone(),
+            enum_values: schema.enum_values.as_ref().map(|values| {
+                values
+                    .iter()
+                    .cloned()
+                    .map(serde_json::Value::String)
+                    .collect()
+            }),
+        }
+    }
+
+    /// Request bodies become a single `in: body` parameter, or one `in: formData` parameter
+    /// per property for form media types.
+    fn convert_request_body(
+        &mut self,
+        body: v3_0::RequestBody,
+        location: &str,
+    ) -> (Vec<String>, Vec<Parameter>) {
+        let (form, other): (Vec<_>, Vec<_>) = body
+            .content
+            .into_iter()
+            .partition(|(media_type, _)| is_form_media_type(media_type));
+
+        if other.is_empty() {
+            let consumes = form
+                .iter()
+                .map(|(media_type, _)| media_type.clone())
+                .collect();
+            let params = form
+                .into_iter()
+                .next()
+                .map(|(media_type, content)| {
+                    let content_location =
+                        format!("{location}/content/{}", escape_json_pointer(&media_type));
+                    self.convert_form_parameters(content, &content_location)
+                })
+                .unwrap_or_default();
+            return (consumes, params);
+        }
+
+        if !form.is_empty() {
+            self.warn(
+                location,
+                "form media types cannot be combined with other request body media types in Swagger 2.0 and were removed".to_string(),
+            );
+        }
+        let consumes = other
+            .iter()
+            .map(|(media_type, _)| media_type.clone())
+            .collect::<Vec<_>>();
+        let index = other
+            .iter()
+            .position(|(media_type, _)| media_type.starts_with("application/json"))
+            .unwrap_or(0);
+        if other
+            .iter()
+            .any(|(_, content)| content.schema != other[index].1.schema)
+        {
+            self.warn(
+                location,
+                format!(
+                    "Swagger 2.0 uses a single body schema for all media types; the `{}` schema was used",
+                    other[index].0
+                ),
+            );
+        }
+        let (media_type, content) = other.into_iter().nth(index).unwrap();
+        let content_location = format!("{location}/content/{}", escape_json_pointer(&media_type));
+        let mut schema = content
+            .schema
+            .map(|schema| self.convert_schema_ref(schema, &format!("{content_location}/schema")))
+            .unwrap_or_default();
+        if schema.example.is_none() {
+            schema.example = first_example(content.examples);
+        }
+        let param = Parameter {
+            name: "body".to_string(),
+            location: "body".to_string(),
+            description: body.description,
+            required: body.required,
+            schema: Some(schema),
+            ..Parameter::default()
+        };
+        (consumes, vec![param])
+    }
+
+    fn convert_form_parameters(
+        &mut self,
+        content: v3_0::MediaType,
+        location: &str,
+    ) -> Vec<Parameter> {
+        let schema = match content.schema {
+            Some(ObjectOrReference::Object(schema)) if schema.properties.is_some() => schema,
+            Some(_) => {
+                self.warn(
+                    location,
+                    "form request bodies must describe their fields as object properties in Swagger 2.0; the body was removed".to_string(),
+                );
+                return vec![];
+            }
+            None => return vec![],
+        };
+        let required = schema.required.unwrap_or_default();
+        schema
+            .properties
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, property)| {
+                let property_location = format!(
+                    "{location}/schema/properties/{}",
+                    escape_json_pointer(&name)
+                );
+                let mut items = self.convert_items(&property, &property_location);
+                if property.format.as_deref() == Some("binary") {
+                    items.items_type = Some("file".to_string());
+                    items.format = None;
+                }
+                let collection_format = if items.items_type.as_deref() == Some("array") {
+                    Some("multi".to_string())
+                } else {
+                    None
+                };
+                Parameter {
+                    required: Some(required.contains(&name)),
+                    name,
+                    location: "formData".to_string(),
+                    description: property.description,
+                    schema: None,
+                    param_type: items.items_type,
+                    format: items.format,
+                    items: items.items.map(|items| *items),
+                    collection_format,
+                    default: items.default,
+                    enum_values: items.enum_values,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the converted response along with the media types it produces.
+    fn convert_response(
+        &mut self,
+        response: v3_0::Response,
+        location: &str,
+    ) -> (Response, Vec<String>) {
+        let content = response.content.unwrap_or_default();
+        let produces = content.keys().cloned().collect::<Vec<_>>();
+
+        let preferred = content
+            .keys()
+            .find(|media_type| media_type.starts_with("application/json"))
+            .or_else(|| content.keys().next())
+            .cloned();
+        let mut schema = None;
+        if let Some(preferred) = &preferred {
+            let preferred_schema = &content[preferred].schema;
+            if content
+                .values()
+                .any(|content| &content.schema != preferred_schema)
+            {
+                self.warn(
+                    location,
+                    format!("Swagger 2.0 uses a single response schema for all media types; the `{preferred}` schema was used"),
+                );
+            }
+            schema = preferred_schema.clone().map(|schema| {
+                let schema_location = format!(
+                    "{location}/content/{}/schema",
+                    escape_json_pointer(preferred)
+                );
+                self.convert_schema_ref(schema, &schema_location)
+            });
+        }
+
+        let mut examples = BTreeMap::<String, serde_json::Value>::new();
+        for (media_type, content) in content {
+            if let Some(v3_0::MediaTypeExample::Examples { examples }) = &content.examples {
+                if examples.len() > 1 {
+                    self.warn(
+                        &format!("{location}/content/{}/examples", escape_json_pointer(&media_type)),
+                        "Swagger 2.0 allows a single example per media type; only the first example was kept".to_string(),
+                    );
+                }
+            }
+            if let Some(example) = first_example(content.examples) {
+                examples.insert(media_type, example);
+            }
+        }
+
+        let headers = response.headers.map(|headers| {
+            headers
+                .into_iter()
+                .filter_map(|(name, header)| {
+                    let header_location = format!("{location}/headers/{}", escape_json_pointer(&name));
+                    match header {
+                        ObjectOrReference::Object(header) => {
+                            let schema = header.schema.unwrap_or_else(|| v3_0::Schema {
+                                schema_type: header.param_type.clone(),
+                                format: header.format.clone(),
+                                ..v3_0::Schema::default()
+                            });
+                            let items = self.convert_items(&schema, &header_location);
+                            let collection_format =
+                                if items.items_type.as_deref() == Some("array") {
+                                    Some("csv".to_string())
+                                } else {
+                                    None
+                                };
+                            Some((
+                                name,
+                                super::Header {
+                                    description: header.description,
+                                    header_type: items.items_type,
+                                    format: items.format,
+                                    items: items.items.map(|items| *items),
+                                    collection_format,
+                                    default: items.default,
+                                    enum_values: items.enum_values,
+                                },
+                            ))
+                        }
+                        ObjectOrReference::Ref { .. } => {
+                            self.warn(
+                                &header_location,
+                                "header references are not supported by Swagger 2.0 and were removed".to_string(),
+                            );
+                            None
+                        }
+                    }
+                })
+                .collect()
+        });
+
+        if response.links.is_some_and(|l| !l.is_empty()) {
+            self.warn(
+                location,
+                "links are not supported by Swagger 2.0 and were removed".to_string(),
+            );
+        }
+
+        let response = Response {
+            description: response.description.unwrap_or_default(),
+            schema,
+            headers,
+            examples: if examples.is_empty() {
+                None
+            } else {
+                Some(examples)
+            },
+        };
+        (response, produces)
+    }
+
+    fn convert_schema_ref(
+        &mut self,
+        schema: ObjectOrReference<v3_0::Schema>,
+        location: &str,
+    ) -> Schema {
+        match schema {
+            ObjectOrReference::Object(schema) => self.convert_schema(schema, location),
+            ObjectOrReference::Ref { ref_path } => Schema {
+                ref_path: Some(convert_ref_path(ref_path)),
+                ..Schema::default()
+            },
+        }
+    }
+
+    fn convert_schema(&mut self, schema: v3_0::Schema, location: &str) -> Schema {
+        if schema.one_of.is_some() || schema.any_of.is_some() || schema.not.is_some() {
+            self.warn(
+                location,
+                "`oneOf`, `anyOf` and `not` are not supported by Swagger 2.0 and were removed"
+                    .to_string(),
+            );
+        }
+
+        let mut extensions = schema
+            .extensions
+            .into_iter()
+            .map(|(name, value)| (name, serde_json::Value::String(value)))
+            .collect::<BTreeMap<_, _>>();
+        // Swagger 2.0 has no notion of null; `x-nullable` is the extension most tools honor.
+        if schema.nullable == Some(true) {
+            extensions.insert("x-nullable".to_string(), serde_json::Value::Bool(true));
+        }
+
+        Schema {
+            ref_path: schema.ref_path.map(convert_ref_path),
+            title: schema.title,
+            description: schema.description,
+            schema_type: schema.schema_type,
+            format: schema.format,
+            enum_values: schema
+                .enum_values
+                .map(|values| values.into_iter().map(serde_json::Value::String).collect()),
+            required: schema.required,
+            items: schema
+                .items
+                .map(|items| Box::new(self.convert_schema(*items, &format!("{location}/items")))),
+            properties: schema.properties.map(|properties| {
+                properties
+                    .into_iter()
+                    .map(|(name, property)| {
+                        let property_location =
+                            format!("{location}/properties/{}", escape_json_pointer(&name));
+                        (name, self.convert_schema(property, &property_location))
+                    })
+                    .collect()
+            }),
+            additional_properties: schema.additional_properties.map(|schema| {
+                let location = format!("{location}/additionalProperties");
+                Box::new(match schema {
+                    ObjectOrReference::Object(schema) => self.convert_schema(*schema, &location),
+                    ObjectOrReference::Ref { ref_path } => Schema {
+                        ref_path: Some(convert_ref_path(ref_path)),
+                        ..Schema::default()
+                    },
+                })
+            }),
+            all_of: schema.all_of.map(|all_of| {
+                all_of
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, schema)| {
+                        self.convert_schema_ref(schema, &format!("{location}/allOf/{i}"))
+                    })
+                    .collect()
+            }),
+            read_only: schema.read_only,
+            example: schema.example,
+            default: schema.default,
+            minimum: schema.minimum,
+            max_length: schema.max_length,
+            min_length: schema.min_length,
+            extensions,
+        }
+    }
+
+    fn convert_security_scheme(
+        &mut self,
+        name: &str,
+        scheme: ObjectOrReference<v3_0::SecurityScheme>,
+    ) -> Option<SecurityScheme> {
+        let location = format!("#/components/securitySchemes/{}", escape_json_pointer(name));
+        let scheme = match scheme {
+            ObjectOrReference::Object(scheme) => scheme,
+            ObjectOrReference::Ref { .. } => {
+                self.warn(
+                    &location,
+                    "security scheme references are not supported by Swagger 2.0 and were removed"
+                        .to_string(),
+                );
+                return None;
+            }
+        };
+        match scheme {
+            v3_0::SecurityScheme::ApiKey {
+                location: key_location,
+                ..
+            } if key_location == "cookie" => {
+                self.warn(
+                    &location,
+                    "API keys sent in cookies are not supported by Swagger 2.0; the security scheme was removed".to_string(),
+                );
+                None
+            }
+            v3_0::SecurityScheme::ApiKey {
+                name,
+                location: key_location,
+            } => Some(SecurityScheme::ApiKey {
+                name,
+                location: key_location,
+            }),
+            v3_0::SecurityScheme::Http { scheme, .. } if scheme.eq_ignore_ascii_case("basic") => {
+                Some(SecurityScheme::Basic)
+            }
+            v3_0::SecurityScheme::Http { scheme, .. } if scheme.eq_ignore_ascii_case("bearer") => {
+                self.warn(
+                    &location,
+                    "bearer authentication is not supported by Swagger 2.0; it is described as an API key in the `Authorization` header".to_string(),
+                );
+                Some(SecurityScheme::ApiKey {
+                    name: "Authorization".to_string(),
+                    location: "header".to_string(),
+                })
+            }
+            v3_0::SecurityScheme::Http { scheme, .. } => {
+                self.warn(
+                    &location,
+                    format!("HTTP `{scheme}` authentication is not supported by Swagger 2.0; the security scheme was removed"),
+                );
+                None
+            }
+            v3_0::SecurityScheme::OAuth2 { flows } => {
+                let mut converted = vec![];
+                if let Some(flow) = flows.implicit {
+                    converted.push(("implicit", Some(flow.authorization_url), None, flow.scopes));
+                }
+                if let Some(flow) = flows.password {
+                    converted.push(("password", None, Some(flow.token_url), flow.scopes));
+                }
+                if let Some(flow) = flows.client_credentials {
+                    converted.push(("application", None, Some(flow.token_url), flow.scopes));
+                }
+                if let Some(flow) = flows.authorization_code {
+                    converted.push((
+                        "accessCode",
+                        Some(flow.authorization_url),
+                        Some(flow.token_url),
+                        flow.scopes,
+                    ));
+                }
+                if converted.len() > 1 {
+                    self.warn(
+                        &location,
+                        "Swagger 2.0 allows a single OAuth 2.0 flow per security scheme; only the first flow was kept".to_string(),
+                    );
+                }
+                match converted.into_iter().next() {
+                    Some((flow, authorization_url, token_url, scopes)) => {
+                        Some(SecurityScheme::OAuth2 {
+                            flow: flow.to_string(),
+                            authorization_url,
+                            token_url,
+                            scopes,
+                        })
+                    }
+                    None => {
+                        self.warn(
+                            &location,
+                            "OAuth 2.0 security schemes without a flow cannot be described in Swagger 2.0; the security scheme was removed".to_string(),
+                        );
+                        None
+                    }
+                }
+            }
+            v3_0::SecurityScheme::OpenIdConnect { .. } => {
+                self.warn(
+                    &location,
+                    "OpenID Connect is not supported by Swagger 2.0; the security scheme was removed".to_string(),
+                );
+                None
+            }
+        }
+    }
+
+    /// Drops references to security schemes that could not be converted. When no requirement is
+    /// left, the security is left out rather than emptied, as an empty list would mark the
+    /// operation as public.
+    fn convert_security(
+        &self,
+        security: Option<Vec<SecurityRequirement>>,
+    ) -> Option<Vec<SecurityRequirement>> {
+        security.and_then(|requirements| {
+            if requirements.is_empty() {
+                return Some(requirements);
+            }
+            let requirements = requirements
+                .into_iter()
+                .filter_map(|requirement| match requirement.requirement {
+                    Some(mut schemes) => {
+                        schemes.retain(|name, _| !self.dropped_security_schemes.contains(name));
+                        if schemes.is_empty() {
+                            None
+                        } else {
+                            Some(SecurityRequirement {
+                                requirement: Some(schemes),
+                            })
+                        }
+                    }
+                    None => Some(requirement),
+                })
+                .collect::<Vec<_>>();
+            Some(requirements).filter(|requirements| !requirements.is_empty())
+        })
+    }
+}
+
+/// Splits a server URL into its scheme, host and base path.
+fn split_url(url: &str) -> (Option<String>, Option<String>, Option<String>) {
+    let (scheme, rest) = match url.split_once("://") {
+        Some((scheme, rest)) => (Some(scheme.to_lowercase()), Some(rest)),
+        None => (None, url.strip_prefix("//")),
+    };
+    let (host, path) = match rest {
+        Some(rest) => match rest.find('/') {
+            Some(i) => (Some(rest[..i].to_string()), &rest[i..]),
+            None => (Some(rest.to_string()), ""),
+        },
+        None => (None, url),
+    };
+    let path = path.trim_end_matches('/');
+    let base_path = if path.is_empty() {
+        None
+    } else if path.starts_with('/') {
+        Some(path.to_string())
+    } else {
+        Some(format!("/{path}"))
+    };
+    (scheme, host.filter(|host| !host.is_empty()), base_path)
+}
+
+fn convert_ref_path(ref_path: String) -> String {
+    for (from, to) in [
+        ("#/components/schemas/", "#/definitions/"),
+        ("#/components/parameters/", "#/parameters/"),
+        ("#/components/responses/", "#/responses/"),
+    ] {
+        if let Some(name) = ref_path.strip_prefix(from) {
+            return format!("{to}{name}");
+        }
+    }
+    ref_path
+}
+
+fn first_example(examples: Option<v3_0::MediaTypeExample>) -> Option<serde_json::Value> {
+    match examples? {
+        v3_0::MediaTypeExample::Example { example } => Some(example),
+        v3_0::MediaTypeExample::Examples { examples } => {
+            examples.into_values().find_map(|example| match example {
+                ObjectOrReference::Object(example) => example.value,
+                ObjectOrReference::Ref { .. } => None,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_splits_the_first_server_into_host_base_path_and_schemes() {
+        let (spec, warnings) = Spec::from_v3_0(v3_0::Spec {
+            servers: Some(vec![
+                v3_0::Server {
+                    url: "https://api.example.com/v1/".to_string(),
+                    ..v3_0::Server::default()
+                },
+                v3_0::Server {
+                    url: "http://api.example.com/v1".to_string(),
+                    ..v3_0::Server::default()
+                },
+                v3_0::Server {
+                    url: "https://other.example.com".to_string(),
+                    ..v3_0::Server::default()
+                },
+            ]),
+            ..v3_0::Spec::default()
+        });
+
+        assert_eq!(spec.swagger, "2.0");
+        assert_eq!(spec.host.as_deref(), Some("api.example.com"));
+        assert_eq!(spec.base_path.as_deref(), Some("/v1"));
+        assert_eq!(
+            spec.schemes,
+            Some(vec!["https".to_string(), "http".to_string()])
+        );
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].location, "#/servers/2");
+    }
+
+    #[test]
+    fn it_converts_request_bodies_into_body_and_form_data_parameters() {
+        let json_body = v3_0::RequestBody {
+            content: BTreeMap::from([(
+                "application/json".to_string(),
+                v3_0::MediaType {
+                    schema: Some(ObjectOrReference::Ref {
+                        ref_path: "#/components/schemas/User".to_string(),
+                    }),
+                    ..v3_0::MediaType::default()
+                },
+            )]),
+            ..v3_0::RequestBody::default()
+        };
+        let form_body = v3_0::RequestBody {
+            content: BTreeMap::from([(
+                "multipart/form-data".to_string(),
+                v3_0::MediaType {
+                    schema: Some(ObjectOrReference::Object(v3_0::Schema {
+                        schema_type: Some("object".to_string()),
+                        required: Some(vec!["name".to_string()]),
+                        properties: Some(BTreeMap::from([
+                            (
+                                "avatar".to_string(),
+                                v3_0::Schema {
+                                    schema_type: Some("string".to_string()),
+                                    format: Some("binary".to_string()),
+                                    ..v3_0::Schema::default()
+                                },
+                            ),
+                            (
+                                "name".to_string(),
+                                v3_0::Schema {
+                                    schema_type: Some("string".to_string()),
+                                    ..v3_0::Schema::default()
+                                },
+                            ),
+                        ])),
+                        ..v3_0::Schema::default()
+                    })),
+                    ..v3_0::MediaType::default()
+                },
+            )]),
+            ..v3_0::RequestBody::default()
+        };
+
+        let mut converter = Converter::default();
+        let (consumes, params) = converter.convert_request_body(json_body, "#");
+        assert_eq!(consumes, vec!["application/json".to_string()]);
+        assert_eq!(params.len(), 1);
+        assert_eq!(params[0].location, "body");
+        assert_eq!(
+            params[0].schema.as_ref().unwrap().ref_path.as_deref(),
+            Some("#/definitions/User")
+        );
+
+        let (consumes, params) = converter.convert_request_body(form_body, "#");
+        assert_eq!(consumes, vec!["multipart/form-data".to_string()]);
+        assert_eq!(params.len(), 2);
+        assert!(params.iter().all(|p| p.location == "formData"));
+        assert_eq!(params[0].param_type.as_deref(), Some("file"));
+        assert_eq!(params[1].required, Some(true));
+        assert!(converter.warnings.is_empty());
+    }
+
+    #[test]
+    fn it_warns_about_schema_features_that_cannot_be_converted() {
+        let mut converter = Converter::default();
+        let schema = converter.convert_schema(
+            v3_0::Schema {
+                nullable: Some(true),
+                any_of: Some(vec![]),
+                ..v3_0::Schema::default()
+            },
+            "#/components/schemas/Pet",
+        );
+
+        assert_eq!(
+            schema.extensions.get("x-nullable"),
+            Some(&serde_json::Value::Bool(true))
+        );
+        assert_eq!(converter.warnings.len(), 1);
+        assert_eq!(converter.warnings[0].location, "#/components/schemas/Pet");
+    }
+
+    #[test]
+    fn it_removes_requirements_for_security_schemes_that_cannot_be_converted() {
+        let (spec, warnings) = Spec::from_v3_0(v3_0::Spec {
+            components: Some(v3_0::Components {
+                security_schemes: Some(BTreeMap::from([
+                    (
+                        "basicAuth".to_string(),
+                        ObjectOrReference::Object(v3_0::SecurityScheme::Http {
+                            scheme: "basic".to_string(),
+                            bearer_format: None,
+                        }),
+                    ),
+                    (
+                        "digestAuth".to_string(),
+                        ObjectOrReference::Object(v3_0::SecurityScheme::Http {
+                            scheme: "digest".to_string(),
+                            bearer_format: None,
+                        }),
+                    ),
+                ])),
+                ..v3_0::Components::default()
+            }),
+            security: Some(vec![
+                SecurityRequirement {
+                    requirement: Some(BTreeMap::from([("basicAuth".to_string(), vec![])])),
+                },
+                SecurityRequirement {
+                    requirement: Some(BTreeMap::from([("digestAuth".to_string(), vec![])])),
+                },
+            ]),
+            ..v3_0::Spec::default()
+        });
+
+        let definitions = spec.security_definitions.unwrap();
+        assert_eq!(definitions.len(), 1);
+        assert_eq!(definitions["basicAuth"], SecurityScheme::Basic);
+        assert_eq!(spec.security.unwrap().len(), 1);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].location,
+            "#/components/securitySchemes/digestAuth"
+        );
+    }
+}
@@ -0,0 +1,10 @@
+//! Support for the Swagger 2.0 (OpenAPI 2.0) specification.
+//!
+//! See the
+//! [specification](https://github.com/OAI/OpenAPI-Specification/blob/main/versions/2.0.md)
+//! for more information.
+
+mod convert;
+mod schema;
+
+pub use self::{convert::*, schema::*};
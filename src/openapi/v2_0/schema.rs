@@ -0,0 +1,320 @@
+//! Schema specification for [Swagger 2.0](https://github.com/OAI/OpenAPI-Specification/blob/main/versions/2.0.md)
+//!
+//! Objects that have the same shape in 2.0 and 3.0 are re-exported from the 3.0 module.
+
+use indexmap::{IndexMap, IndexSet};
+use std::collections::BTreeMap;
+
+pub use crate::openapi::v3_0::{
+    Contact, ExternalDoc, License, ObjectOrReference, SecurityRequirement, Tag,
+};
+
+/// top level document
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Spec {
+    /// Specifies the Swagger Specification version being used. The value MUST be `"2.0"`.
+    pub swagger: String,
+    /// Provides metadata about the API. The metadata can be used by the clients if needed.
+    pub info: Info,
+    /// The host (name or ip) serving the API, including the port if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// The base path on which the API is served, which is relative to the `host`. It MUST
+    /// start with a leading slash.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "basePath")]
+    pub base_path: Option<String>,
+    /// The transfer protocol of the API. Values MUST be from the list: `http`, `https`, `ws`,
+    /// `wss`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schemes: Option<Vec<String>>,
+    /// A list of MIME types the APIs can consume.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumes: Option<Vec<String>>,
+    /// A list of MIME types the APIs can produce.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub produces: Option<Vec<String>>,
+    /// The available paths and operations for the API.
+    pub paths: IndexMap<String, PathItem>,
+    /// An object to hold data types produced and consumed by operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub definitions: Option<BTreeMap<String, Schema>>,
+    /// An object to hold parameters that can be used across operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<BTreeMap<String, Parameter>>,
+    /// An object to hold responses that can be used across operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub responses: Option<BTreeMap<String, Response>>,
+    /// Security scheme definitions that can be used across the specification.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "securityDefinitions"
+    )]
+    pub security_definitions: Option<BTreeMap<String, SecurityScheme>>,
+    /// A declaration of which security schemes are applied for the API as a whole.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<Vec<SecurityRequirement>>,
+    /// A list of tags used by the specification with additional metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<IndexSet<Tag>>,
+    /// Additional external documentation.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "externalDocs")]
+    pub external_docs: Option<ExternalDoc>,
+}
+
+/// The object provides metadata about the API.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/2.0.md#infoObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Info {
+    /// The title of the application.
+    pub title: String,
+    /// A short description of the application. GFM syntax can be used for rich text
+    /// representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The Terms of Service for the API.
+    #[serde(rename = "termsOfService", skip_serializing_if = "Option::is_none")]
+    pub terms_of_service: Option<String>,
+    /// The contact information for the exposed API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact: Option<Contact>,
+    /// The license information for the exposed API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<License>,
+    /// Provides the version of the application API.
+    pub version: String,
+}
+
+/// Describes the operations available on a single path.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/2.0.md#pathItemObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct PathItem {
+    /// Allows for an external definition of this path item.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "$ref")]
+    pub reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub get: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub put: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patch: Option<Operation>,
+    /// A list of parameters that are applicable for all the operations described under this
+    /// path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Vec<ObjectOrReference<Parameter>>>,
+}
+
+/// Describes a single API operation on a path.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/2.0.md#operationObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Operation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "externalDocs")]
+    pub external_docs: Option<ExternalDoc>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "operationId")]
+    pub operation_id: Option<String>,
+    /// A list of MIME types the operation can consume.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumes: Option<Vec<String>>,
+    /// A list of MIME types the operation can produce.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub produces: Option<Vec<String>>,
+    /// A list of parameters that are applicable for this operation. Request bodies are
+    /// described by a single `body` parameter or by `formData` parameters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Vec<ObjectOrReference<Parameter>>>,
+    /// The list of possible responses as they are returned from executing this operation.
+    pub responses: BTreeMap<String, Response>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<Vec<SecurityRequirement>>,
+}
+
+/// Describes a single operation parameter.
+///
+/// `in: body` parameters carry a `schema`; every other location is described with the
+/// primitive `type`, `format` and `items` fields.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/2.0.md#parameterObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Parameter {
+    /// The name of the parameter.
+    pub name: String,
+    /// The location of the parameter. Possible values are `query`, `header`, `path`,
+    /// `formData` or `body`.
+    #[serde(rename = "in")]
+    pub location: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+    /// The schema defining the type used for the body parameter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Schema>,
+    /// The type of the parameter. One of `string`, `number`, `integer`, `boolean`, `array`
+    /// or `file`.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
+    pub param_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// Describes the type of items in the array when `type` is `array`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Items>,
+    /// Determines the format of the array if type array is used. One of `csv`, `ssv`, `tsv`,
+    /// `pipes` or `multi`.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "collectionFormat")]
+    pub collection_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "enum")]
+    pub enum_values: Option<Vec<serde_json::Value>>,
+}
+
+/// A limited subset of JSON-Schema's items object, used by parameters and headers.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/2.0.md#itemsObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Items {
+    #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
+    pub items_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<Items>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "collectionFormat")]
+    pub collection_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "enum")]
+    pub enum_values: Option<Vec<serde_json::Value>>,
+}
+
+/// Describes a single response from an API Operation.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/2.0.md#responseObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Response {
+    /// A short description of the response.
+    pub description: String,
+    /// A definition of the response structure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Schema>,
+    /// A list of headers that are sent with the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<BTreeMap<String, Header>>,
+    /// Examples of the response message, keyed by MIME type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub examples: Option<BTreeMap<String, serde_json::Value>>,
+}
+
+/// Describes a single response header.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/2.0.md#headerObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Header {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
+    pub header_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Items>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "collectionFormat")]
+    pub collection_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "enum")]
+    pub enum_values: Option<Vec<serde_json::Value>>,
+}
+
+/// The Schema Object allows the definition of input and output data types. It is based on
+/// JSON Schema Draft 4 and does not support `oneOf`, `anyOf`, `not` or `nullable`.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/2.0.md#schemaObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Schema {
+    #[serde(skip_serializing_if = "Option::is_none", rename = "$ref")]
+    pub ref_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
+    pub schema_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "enum")]
+    pub enum_values: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<Schema>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<BTreeMap<String, Schema>>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "additionalProperties"
+    )]
+    pub additional_properties: Option<Box<Schema>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "allOf")]
+    pub all_of: Option<Vec<Schema>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "readOnly")]
+    pub read_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub example: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "maxLength")]
+    pub max_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "minLength")]
+    pub min_length: Option<u32>,
+    /// [Vendor extensions](https://github.com/OAI/OpenAPI-Specification/blob/main/versions/2.0.md#vendorExtensions),
+    /// e.g. `x-nullable`.
+    #[serde(flatten)]
+    pub extensions: BTreeMap<String, serde_json::Value>,
+}
+
+/// Allows the definition of a security scheme that can be used by the operations.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/2.0.md#securitySchemeObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum SecurityScheme {
+    #[serde(rename = "basic")]
+    Basic,
+    #[serde(rename = "apiKey")]
+    ApiKey {
+        name: String,
+        #[serde(rename = "in")]
+        location: String,
+    },
+    #[serde(rename = "oauth2")]
+    OAuth2 {
+        /// One of `implicit`, `password`, `application` or `accessCode`.
+        flow: String,
+        #[serde(rename = "authorizationUrl", skip_serializing_if = "Option::is_none")]
+        authorization_url: Option<String>,
+        #[serde(rename = "tokenUrl", skip_serializing_if = "Option::is_none")]
+        token_url: Option<String>,
+        scopes: BTreeMap<String, String>,
+    },
+}
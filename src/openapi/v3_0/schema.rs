@@ -395,6 +395,10 @@ pub struct Parameter {
     /// `header` - `simple`; for cookie - `form`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub style: Option<ParameterStyle>,
+    /// When true, array values generate a separate parameter for each value. Defaults to true
+    /// for the `form` style.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explode: Option<bool>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
@@ -402,6 +406,7 @@ pub struct Parameter {
 pub enum ParameterStyle {
     Form,
     Simple,
+    DeepObject,
 }
 
 // FIXME: Verify against OpenAPI 3.0
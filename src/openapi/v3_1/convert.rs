@@ -0,0 +1,394 @@
+//! Conversion of OpenAPI 3.0 definitions into OpenAPI 3.1 definitions.
+
+use super::{
+    Components, Encoding, Header, Info, License, MediaType, ObjectOrReference, Operation,
+    Parameter, PathItem, RequestBody, Response, Schema, SchemaType, Spec, NULL_TYPE,
+};
+use crate::openapi::v3_0;
+use std::collections::BTreeMap;
+
+impl From<v3_0::Spec> for Spec {
+    fn from(spec: v3_0::Spec) -> Self {
+        Spec {
+            openapi: String::from("3.1.0"),
+            info: spec.info.into(),
+            json_schema_dialect: None,
+            servers: spec.servers,
+            paths: spec
+                .paths
+                .into_iter()
+                .map(|(path, item)| (path, item.into()))
+                .collect(),
+            webhooks: None,
+            components: spec.components.map(Components::from),
+            security: spec.security,
+            tags: spec.tags,
+            external_docs: spec.external_docs,
+        }
+    }
+}
+
+impl From<v3_0::Info> for Info {
+    fn from(info: v3_0::Info) -> Self {
+        Info {
+            title: info.title,
+            summary: None,
+            description: info.description,
+            terms_of_service: info.terms_of_service,
+            version: info.version,
+            contact: info.contact,
+            license: info.license.map(License::from),
+        }
+    }
+}
+
+impl From<v3_0::License> for License {
+    fn from(license: v3_0::License) -> Self {
+        License {
+            name: license.name,
+            identifier: None,
+            url: license.url,
+        }
+    }
+}
+
+impl From<v3_0::Components> for Components {
+    fn from(components: v3_0::Components) -> Self {
+        Components {
+            schemas: components.schemas.map(|schemas| {
+                schemas
+                    .into_iter()
+                    .map(|(name, schema)| (name, schema.into()))
+                    .collect()
+            }),
+            responses: components.responses.map(convert_map),
+            parameters: components.parameters.map(convert_map),
+            examples: components.examples,
+            request_bodies: components.request_bodies.map(convert_map),
+            headers: components.headers.map(convert_map),
+            security_schemes: components.security_schemes,
+            links: components.links,
+            callbacks: components.callbacks,
+            path_items: None,
+        }
+    }
+}
+
+impl From<v3_0::PathItem> for PathItem {
+    fn from(item: v3_0::PathItem) -> Self {
+        PathItem {
+            reference: item.reference,
+            summary: item.summary,
+            description: item.description,
+            get: item.get.map(Operation::from),
+            put: item.put.map(Operation::from),
+            post: item.post.map(Operation::from),
+            delete: item.delete.map(Operation::from),
+            options: item.options.map(Operation::from),
+            head: item.head.map(Operation::from),
+            patch: item.patch.map(Operation::from),
+            trace: item.trace.map(Operation::from),
+            servers: item.servers,
+            parameters: item.parameters.map(convert_vec),
+        }
+    }
+}
+
+impl From<v3_0::Operation> for Operation {
+    fn from(op: v3_0::Operation) -> Self {
+        Operation {
+            tags: op.tags,
+            summary: op.summary,
+            description: op.description,
+            external_docs: op.external_docs,
+            operation_id: op.operation_id,
+            parameters: op.parameters.map(convert_vec),
+            request_body: op.request_body.map(convert_ref),
+            responses: if op.responses.is_empty() {
+                None
+            } else {
+                Some(
+                    op.responses
+                        .into_iter()
+                        .map(|(code, response)| (code, response.into()))
+                        .collect(),
+                )
+            },
+            callbacks: op.callbacks,
+            deprecated: op.deprecated,
+            security: op.security,
+            servers: op.servers,
+        }
+    }
+}
+
+impl From<v3_0::Parameter> for Parameter {
+    fn from(param: v3_0::Parameter) -> Self {
+        Parameter {
+            name: param.name,
+            location: param.location,
+            description: param.description,
+            required: param.required,
+            schema: param.schema.map(Schema::from),
+            style: param.style,
+            explode: param.explode,
+        }
+    }
+}
+
+impl From<v3_0::Response> for Response {
+    fn from(response: v3_0::Response) -> Self {
+        Response {
+            description: response.description,
+            headers: response.headers.map(convert_map),
+            content: response.content.map(|content| {
+                content
+                    .into_iter()
+                    .map(|(media_type, content)| (media_type, content.into()))
+                    .collect()
+            }),
+            links: response.links,
+        }
+    }
+}
+
+impl From<v3_0::Header> for Header {
+    fn from(header: v3_0::Header) -> Self {
+        Header {
+            description: header.description,
+            required: header.required,
+            schema: header.schema.map(Schema::from),
+        }
+    }
+}
+
+impl From<v3_0::RequestBody> for RequestBody {
+    fn from(body: v3_0::RequestBody) -> Self {
+        RequestBody {
+            description: body.description,
+            content: body
+                .content
+                .into_iter()
+                .map(|(media_type, content)| (media_type, content.into()))
+                .collect(),
+            required: body.required,
+        }
+    }
+}
+
+impl From<v3_0::MediaType> for MediaType {
+    fn from(media_type: v3_0::MediaType) -> Self {
+        MediaType {
+            schema: media_type.schema.map(Schema::from),
+            examples: media_type.examples,
+            encoding: media_type.encoding.map(|encoding| {
+                encoding
+                    .into_iter()
+                    .map(|(name, encoding)| (name, encoding.into()))
+                    .collect()
+            }),
+        }
+    }
+}
+
+impl From<v3_0::Encoding> for Encoding {
+    fn from(encoding: v3_0::Encoding) -> Self {
+        Encoding {
+            content_type: encoding.content_type,
+            headers: encoding.headers.map(convert_map),
+            style: encoding.style,
+            explode: encoding.explode,
+            allow_reserved: encoding.allow_reserved,
+        }
+    }
+}
+
+impl From<ObjectOrReference<v3_0::Schema>> for Schema {
+    fn from(schema: ObjectOrReference<v3_0::Schema>) -> Self {
+        match schema {
+            ObjectOrReference::Object(schema) => schema.into(),
+            ObjectOrReference::Ref { ref_path } => Schema {
+                ref_path: Some(ref_path),
+                ..Schema::default()
+            },
+        }
+    }
+}
+
+impl From<v3_0::Schema> for Schema {
+    fn from(schema: v3_0::Schema) -> Self {
+        let nullable = schema.nullable == Some(true);
+
+        let mut any_of = schema
+            .any_of
+            .map(|any_of| any_of.into_iter().map(Schema::from).collect::<Vec<_>>());
+
+        // `nullable` no longer exists; null is expressed as one more JSON Schema type.
+        let schema_type = match schema.schema_type {
+            Some(t) if nullable && t != NULL_TYPE => {
+                Some(SchemaType::Multiple(vec![t, NULL_TYPE.to_string()]))
+            }
+            Some(t) => Some(SchemaType::Single(t)),
+            None if nullable => match &mut any_of {
+                Some(any_of) => {
+                    if !any_of.iter().any(Schema::is_null) {
+                        any_of.push(Schema {
+                            schema_type: Some(SchemaType::Single(NULL_TYPE.to_string())),
+                            ..Schema::default()
+                        });
+                    }
+                    None
+                }
+                None if schema.ref_path.is_none() && schema.all_of.is_none() => {
+                    Some(SchemaType::Single(NULL_TYPE.to_string()))
+                }
+                None => None,
+            },
+            None => None,
+        };
+
+        let enum_values = schema.enum_values.map(|values| {
+            let mut values = values
+                .into_iter()
+                .map(serde_json::Value::String)
+                .collect::<Vec<_>>();
+            if nullable {
+                values.push(serde_json::Value::Null);
+            }
+            values
+        });
+
+        let not = schema.not.and_then(|mut not| match not.len() {
+            0 => None,
+            1 => Some(Box::new(Schema::from(not.remove(0)))),
+            _ => Some(Box::new(Schema {
+                any_of: Some(not.into_iter().map(Schema::from).collect()),
+                ..Schema::default()
+            })),
+        });
+
+        Schema {
+            ref_path: schema.ref_path,
+            title: schema.title,
+            description: schema.description,
+            schema_type,
+            format: schema.format,
+            enum_values,
+            const_value: None,
+            required: schema.required,
+            items: schema.items.map(|items| Box::new(Schema::from(*items))),
+            properties: schema.properties.map(|properties| {
+                properties
+                    .into_iter()
+                    .map(|(name, schema)| (name, schema.into()))
+                    .collect()
+            }),
+            additional_properties: schema.additional_properties.map(|schema| match schema {
+                ObjectOrReference::Object(schema) => Box::new(Schema::from(*schema)),
+                ObjectOrReference::Ref { ref_path } => Box::new(Schema {
+                    ref_path: Some(ref_path),
+                    ..Schema::default()
+                }),
+            }),
+            read_only: schema.read_only,
+            write_only: None,
+            examples: schema.example.map(|example| vec![example]),
+            default: schema.default,
+            minimum: schema.minimum,
+            all_of: schema
+                .all_of
+                .map(|all_of| all_of.into_iter().map(Schema::from).collect()),
+            one_of: schema
+                .one_of
+                .map(|one_of| one_of.into_iter().map(Schema::from).collect()),
+            any_of,
+            not,
+            max_length: schema.max_length,
+            min_length: schema.min_length,
+            extensions: schema.extensions,
+        }
+    }
+}
+
+fn convert_ref<T, U>(value: ObjectOrReference<T>) -> ObjectOrReference<U>
+where
+    U: From<T>,
+{
+    match value {
+        ObjectOrReference::Object(value) => ObjectOrReference::Object(value.into()),
+        ObjectOrReference::Ref { ref_path } => ObjectOrReference::Ref { ref_path },
+    }
+}
+
+fn convert_vec<T, U>(values: Vec<ObjectOrReference<T>>) -> Vec<ObjectOrReference<U>>
+where
+    U: From<T>,
+{
+    values.into_iter().map(convert_ref).collect()
+}
+
+fn convert_map<T, U>(
+    values: BTreeMap<String, ObjectOrReference<T>>,
+) -> BTreeMap<String, ObjectOrReference<U>>
+where
+    U: From<T>,
+{
+    values
+        .into_iter()
+        .map(|(name, value)| (name, convert_ref(value)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_converts_nullable_into_a_type_array() {
+        let schema = Schema::from(v3_0::Schema {
+            schema_type: Some("string".to_string()),
+            nullable: Some(true),
+            example: Some(serde_json::Value::String("example".to_string())),
+            ..v3_0::Schema::default()
+        });
+
+        assert_eq!(
+            schema.schema_type,
+            Some(SchemaType::Multiple(vec![
+                "string".to_string(),
+                "null".to_string()
+            ]))
+        );
+        assert_eq!(
+            schema.examples,
+            Some(vec![serde_json::Value::String("example".to_string())])
+        );
+    }
+
+    #[test]
+    fn it_converts_an_untyped_nullable_schema_into_the_null_type() {
+        let schema = Schema::from(v3_0::Schema {
+            nullable: Some(true),
+            example: Some(serde_json::Value::Null),
+            ..v3_0::Schema::default()
+        });
+
+        assert!(schema.is_null());
+    }
+
+    #[test]
+    fn it_adds_null_to_any_of() {
+        let schema = Schema::from(v3_0::Schema {
+            nullable: Some(true),
+            any_of: Some(vec![ObjectOrReference::Object(v3_0::Schema {
+                schema_type: Some("string".to_string()),
+                ..v3_0::Schema::default()
+            })]),
+            ..v3_0::Schema::default()
+        });
+
+        let any_of = schema.any_of.unwrap();
+        assert_eq!(any_of.len(), 2);
+        assert!(any_of[1].is_null());
+    }
+}
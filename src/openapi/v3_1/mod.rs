@@ -0,0 +1,11 @@
+//! Support for OpenApi version 3.1.0 specification.
+//!
+//! See the
+//! [specification](https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md)
+//! for more information.
+
+mod components;
+mod convert;
+mod schema;
+
+pub use self::{components::*, schema::*};
@@ -0,0 +1,447 @@
+//! Schema specification for [OpenAPI 3.1.0](https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md)
+//!
+//! Objects that did not change between 3.0 and 3.1 are re-exported from the 3.0 module.
+
+use indexmap::{IndexMap, IndexSet};
+use std::collections::{BTreeMap, HashMap};
+
+pub use crate::openapi::v3_0::{
+    AuthorizationCodeFlow, Callback, ClientCredentialsFlow, Contact, Example, ExternalDoc, Flows,
+    ImplicitFlow, Link, MediaTypeExample, ParameterStyle, PasswordFlow, SecurityRequirement,
+    SecurityScheme, Server, ServerVariable, Tag,
+};
+
+use super::{
+    super::Error,
+    super::Result,
+    super::MINIMUM_OPENAPI31_VERSION,
+    components::{Components, ObjectOrReference},
+};
+
+impl Spec {
+    pub fn validate_version(&self) -> Result<semver::Version> {
+        let spec_version = &self.openapi;
+        let sem_ver = semver::Version::parse(spec_version)?;
+        let required_version = semver::VersionReq::parse(MINIMUM_OPENAPI31_VERSION).unwrap();
+        if required_version.matches(&sem_ver) {
+            Ok(sem_ver)
+        } else {
+            Err(Error::UnsupportedSpecFileVersion(sem_ver))
+        }
+    }
+}
+
+/// top level document
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Spec {
+    /// This string MUST be the version number of the
+    /// [OpenAPI Specification](https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md#versions)
+    /// that the OpenAPI document uses.
+    pub openapi: String,
+    /// Provides metadata about the API. The metadata MAY be used by tooling as required.
+    pub info: Info,
+    /// The default value for the `$schema` keyword within Schema Objects contained within this
+    /// OAS document. This MUST be in the form of a URI.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "jsonSchemaDialect")]
+    pub json_schema_dialect: Option<String>,
+    /// An array of Server Objects, which provide connectivity information to a target server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub servers: Option<Vec<Server>>,
+
+    /// The available paths and operations for the API.
+    #[serde(default)]
+    pub paths: IndexMap<String, PathItem>,
+
+    /// The incoming webhooks that MAY be received as part of this API and that the API consumer
+    /// MAY choose to implement. The key name is a unique string to refer to each webhook.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhooks: Option<BTreeMap<String, ObjectOrReference<PathItem>>>,
+
+    /// An element to hold various schemas for the specification.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Components>,
+
+    /// A declaration of which security mechanisms can be used across the API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<Vec<SecurityRequirement>>,
+
+    /// A list of tags used by the specification with additional metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<IndexSet<Tag>>,
+
+    /// Additional external documentation.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "externalDocs")]
+    pub external_docs: Option<ExternalDoc>,
+}
+
+/// General information about the API.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md#infoObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Info {
+    /// The title of the application.
+    pub title: String,
+    /// A short summary of the API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// A short description of the application. CommonMark syntax MAY be used for rich text representation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A URL to the Terms of Service for the API. MUST be in the format of a URL.
+    #[serde(rename = "termsOfService", skip_serializing_if = "Option::is_none")]
+    pub terms_of_service: Option<String>,
+    /// The version of the OpenAPI document.
+    pub version: String,
+    /// The contact information for the exposed API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact: Option<Contact>,
+    /// The license information for the exposed API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<License>,
+}
+
+/// License information for the exposed API.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md#licenseObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct License {
+    /// The license name used for the API.
+    pub name: String,
+    /// An [SPDX](https://spdx.org/spdx-specification-21-web-version#h.jxpfx0ykyb60) license
+    /// expression for the API. The `identifier` field is mutually exclusive of the `url` field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    /// A URL to the license used for the API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Describes the operations available on a single path.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md#pathItemObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct PathItem {
+    /// Allows for a referenced definition of this path item.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "$ref")]
+    pub reference: Option<String>,
+
+    /// An optional, string summary, intended to apply to all operations in this path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// An optional, string description, intended to apply to all operations in this path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// A definition of a GET operation on this path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub get: Option<Operation>,
+    /// A definition of a PUT operation on this path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub put: Option<Operation>,
+    /// A definition of a POST operation on this path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post: Option<Operation>,
+    /// A definition of a DELETE operation on this path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete: Option<Operation>,
+    /// A definition of a OPTIONS operation on this path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Operation>,
+    /// A definition of a HEAD operation on this path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head: Option<Operation>,
+    /// A definition of a PATCH operation on this path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patch: Option<Operation>,
+    /// A definition of a TRACE operation on this path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trace: Option<Operation>,
+
+    /// An alternative `server` array to service all operations in this path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub servers: Option<Vec<Server>>,
+
+    /// A list of parameters that are applicable for all the operations described under this
+    /// path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Vec<ObjectOrReference<Parameter>>>,
+}
+
+/// Describes a single API operation on a path.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md#operationObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Operation {
+    /// A list of tags for API documentation control.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// A short summary of what the operation does.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// A verbose explanation of the operation behavior.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Additional external documentation for this operation.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "externalDocs")]
+    pub external_docs: Option<ExternalDoc>,
+    /// Unique string used to identify the operation.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "operationId")]
+    pub operation_id: Option<String>,
+
+    /// A list of parameters that are applicable for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Vec<ObjectOrReference<Parameter>>>,
+
+    /// The request body applicable for this operation.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "requestBody")]
+    pub request_body: Option<ObjectOrReference<RequestBody>>,
+
+    /// The list of possible responses as they are returned from executing this operation.
+    /// Unlike 3.0, the Responses Object is optional.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub responses: Option<BTreeMap<String, Response>>,
+
+    /// A map of possible out-of band callbacks related to the parent operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callbacks: Option<BTreeMap<String, Callback>>,
+
+    /// Declares this operation to be deprecated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<bool>,
+
+    /// A declaration of which security mechanisms can be used for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<Vec<SecurityRequirement>>,
+
+    /// An alternative `server` array to service this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub servers: Option<Vec<Server>>,
+}
+
+/// Describes a single operation parameter.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md#parameterObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Parameter {
+    /// The name of the parameter.
+    pub name: String,
+    /// The location of the parameter. Possible values are `query`, `header`, `path` or `cookie`.
+    #[serde(rename = "in")]
+    pub location: String,
+    /// A brief description of the parameter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Schema>,
+    /// Describes how the parameter value will be serialized depending on the type of the
+    /// parameter value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ParameterStyle>,
+    /// When true, array values generate a separate parameter for each value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explode: Option<bool>,
+}
+
+/// The JSON Schema type of the `null` value.
+pub const NULL_TYPE: &str = "null";
+
+/// The value of the `type` keyword. JSON Schema 2020-12 allows either a single type or an
+/// array of types, which replaces the `nullable` keyword of OpenAPI 3.0.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum SchemaType {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+/// The Schema Object allows the definition of input and output data types. In OpenAPI 3.1
+/// it is a superset of [JSON Schema Draft 2020-12](https://json-schema.org/draft/2020-12/).
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md#schemaObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Schema {
+    /// Reference to another schema. Unlike 3.0, sibling keywords are allowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "$ref")]
+    pub ref_path: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type")]
+    pub schema_type: Option<SchemaType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "enum")]
+    pub enum_values: Option<Vec<serde_json::Value>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "const")]
+    pub const_value: Option<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<Schema>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<BTreeMap<String, Schema>>,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "additionalProperties"
+    )]
+    pub additional_properties: Option<Box<Schema>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", rename = "readOnly")]
+    pub read_only: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", rename = "writeOnly")]
+    pub write_only: Option<bool>,
+
+    /// Example instances for this schema. Replaces the single `example` keyword of 3.0, which
+    /// is deprecated in 3.1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Vec<serde_json::Value>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<serde_json::Value>,
+
+    #[serde(rename = "allOf", skip_serializing_if = "Option::is_none")]
+    pub all_of: Option<Vec<Schema>>,
+
+    #[serde(rename = "oneOf", skip_serializing_if = "Option::is_none")]
+    pub one_of: Option<Vec<Schema>>,
+
+    #[serde(rename = "anyOf", skip_serializing_if = "Option::is_none")]
+    pub any_of: Option<Vec<Schema>>,
+
+    #[serde(rename = "not", skip_serializing_if = "Option::is_none")]
+    pub not: Option<Box<Schema>>,
+
+    #[serde(rename = "maxLength", skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u32>,
+
+    #[serde(rename = "minLength", skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u32>,
+
+    /// [Specification extensions](https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md#specificationExtensions)
+    #[serde(flatten)]
+    pub extensions: HashMap<String, String>,
+}
+
+impl Schema {
+    /// Returns true if this schema only allows `null`.
+    pub fn is_null(&self) -> bool {
+        matches!(&self.schema_type, Some(SchemaType::Single(t)) if t == NULL_TYPE)
+    }
+}
+
+/// Describes a single response from an API Operation.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md#responseObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Response {
+    /// A short description of the response.
+    pub description: Option<String>,
+
+    /// Maps a header name to its definition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<BTreeMap<String, ObjectOrReference<Header>>>,
+
+    /// A map containing descriptions of potential response payloads.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<BTreeMap<String, MediaType>>,
+
+    /// A map of operations links that can be followed from the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links: Option<BTreeMap<String, ObjectOrReference<Link>>>,
+}
+
+/// The Header Object follows the structure of the Parameter Object, without `name` and `in`.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md#headerObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Header {
+    /// A brief description of the header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Schema>,
+}
+
+/// Describes a single request body.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md#requestBodyObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct RequestBody {
+    /// A brief description of the request body.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The content of the request body.
+    pub content: BTreeMap<String, MediaType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+}
+
+/// Each Media Type Object provides schema and examples for the media type identified by its key.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md#mediaTypeObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct MediaType {
+    /// The schema defining the content of the request, response, or parameter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Schema>,
+
+    /// Example of the media type.
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub examples: Option<MediaTypeExample>,
+
+    /// A map between a property name and its encoding information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<BTreeMap<String, Encoding>>,
+}
+
+/// A single encoding definition applied to a single schema property.
+///
+/// See <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md#encodingObject>.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+pub struct Encoding {
+    /// The Content-Type for encoding a specific property.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "contentType")]
+    pub content_type: Option<String>,
+
+    /// A map allowing additional information to be provided as headers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<BTreeMap<String, ObjectOrReference<Header>>>,
+
+    /// Describes how a specific property value will be serialized depending on its type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+
+    /// When this is true, property values of type `array` or `object` generate
+    /// separate parameters for each value of the array, or key-value-pair of the map.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explode: Option<bool>,
+
+    /// Determines whether the parameter value SHOULD allow reserved characters.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "allowReserved")]
+    pub allow_reserved: Option<bool>,
+}
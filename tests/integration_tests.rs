@@ -5,6 +5,7 @@ macro_rules! test_fixture {
             let filename = get_fixture($filename);
             let options = postman2openapi::TranspileOptions {
                 format: postman2openapi::TargetFormat::Json,
+                ..postman2openapi::TranspileOptions::default()
             };
             match postman2openapi::from_path(&filename, options) {
                 Ok(_oas) => assert!(true),
@@ -33,10 +34,70 @@ test_fixture!(
 );
 test_fixture!(it_parses_oauth2_code_collection, "oauth2-code.postman.json");
 test_fixture!(it_parses_api_key_collection, "api-key.postman.json");
+test_fixture!(it_parses_environment_collection, "environment.postman.json");
+test_fixture!(
+    it_parses_required_properties_collection,
+    "required-properties.postman.json"
+);
+test_fixture!(
+    it_parses_shared_schemas_collection,
+    "shared-schemas.postman.json"
+);
 test_fixture!(
     it_parses_empty_header_object_collection,
     "empty-header-object.postman.json"
 );
+test_fixture!(it_parses_nullable_collection, "nullable.postman.json");
+test_fixture!(it_parses_enums_collection, "enums.postman.json");
+test_fixture!(
+    it_parses_dictionaries_collection,
+    "dictionaries.postman.json"
+);
+test_fixture!(it_parses_string_urls_collection, "string-urls.postman.json");
+test_fixture!(
+    it_parses_server_variables_collection,
+    "server-variables.postman.json"
+);
+test_fixture!(it_parses_base_paths_collection, "base-paths.postman.json");
+test_fixture!(
+    it_parses_multiple_hosts_collection,
+    "multiple-hosts.postman.json"
+);
+test_fixture!(
+    it_parses_duplicate_paths_collection,
+    "duplicate-paths.postman.json"
+);
+test_fixture!(it_parses_literal_ids_collection, "literal-ids.postman.json");
+test_fixture!(
+    it_parses_http_methods_collection,
+    "http-methods.postman.json"
+);
+test_fixture!(
+    it_parses_path_parameters_collection,
+    "path-parameters.postman.json"
+);
+test_fixture!(
+    it_parses_path_variable_types_collection,
+    "path-variable-types.postman.json"
+);
+test_fixture!(
+    it_parses_query_parameters_collection,
+    "query-parameters.postman.json"
+);
+test_fixture!(
+    it_parses_disabled_params_collection,
+    "disabled-params.postman.json"
+);
+test_fixture!(it_parses_cookies_collection, "cookies.postman.json");
+test_fixture!(it_parses_cookie_auth_collection, "cookie-auth.postman.json");
+test_fixture!(
+    it_parses_response_cookies_collection,
+    "response-cookies.postman.json"
+);
+test_fixture!(
+    it_parses_reordered_path_parameters_collection,
+    "reordered-path-parameters.postman.json"
+);
 
 fn get_fixture(filename: &str) -> String {
     let filename: std::path::PathBuf = [env!("CARGO_MANIFEST_DIR"), "./tests/fixtures/", filename]